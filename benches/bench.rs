@@ -4,7 +4,6 @@
 extern crate test;
 
 extern crate linalg;
-extern crate mat4;
 
 
 use test::Bencher;
@@ -23,12 +22,28 @@ fn bench_matrix_mul(b: &mut Bencher) {
 }
 
 #[bench]
-fn bench_mat4_mul(b: &mut Bencher) {
-    let m0 = mat4::new_identity::<usize>();
-    let m1 = mat4::new_identity::<usize>();
-    let mut out = mat4::new_identity::<usize>();
+fn bench_model_view_projection(b: &mut Bencher) {
+    let eye: Vector<f32> = Vector::from([0.0, 2.0, 5.0]);
+    let target: Vector<f32> = Vector::from([0.0, 0.0, 0.0]);
+    let up: Vector<f32> = Vector::from([0.0, 1.0, 0.0]);
+    let offset: Vector<f32> = Vector::from([1.0, 0.0, -1.0]);
 
     b.iter(move || {
-        mat4::mul(&mut out, &m0, &m1);
+        let model = &Matrix::translation(&offset) * &Matrix::rotation_y(0.5);
+        let view = Matrix::look_at_rh(&eye, &target, &up);
+        let projection = Matrix::perspective(1.0, 16.0 / 9.0, 0.1, 100.0);
+
+        &(&projection * &view) * &model
+    });
+}
+
+#[bench]
+fn bench_transform_point(b: &mut Bencher) {
+    let mvp: Matrix<f32> = &Matrix::perspective(1.0, 16.0 / 9.0, 0.1, 100.0) *
+        &Matrix::translation(&Vector::from([0.0, 0.0, -5.0]));
+    let point: Vector<f32> = Vector::from([1.0, 2.0, 3.0]);
+
+    b.iter(move || {
+        mvp.transform_point(&point)
     });
 }
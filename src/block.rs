@@ -0,0 +1,231 @@
+use alloc::vec::Vec;
+
+use zero::Zero;
+
+use super::matrix::Matrix;
+
+
+/// copies the `rows` x `cols` submatrix of `m` whose top left element is `(row, col)`
+#[inline]
+fn submatrix<T: Clone>(m: &Matrix<T>, row: usize, col: usize, rows: usize, cols: usize) -> Matrix<T> {
+    Matrix::from_fn(rows, cols, |i, j| m[row + i][col + j].clone())
+}
+
+#[inline]
+fn assert_sizes_sum(op: &str, what: &str, sizes: &[usize], total: usize) {
+    let sum: usize = sizes.iter().sum();
+    assert!(sum == total, "{} {} sum to {}, expected {}", op, what, sum, total);
+}
+
+
+impl<T> Matrix<T>
+    where T: Clone,
+{
+    /// concatenates matrices with the same number of rows left to right, an empty slice gives
+    /// a 0x0 matrix
+    #[inline]
+    pub fn hstack(matrices: &[Matrix<T>]) -> Matrix<T> {
+        let rows = matrices.first().map_or(0, |m| m.rows());
+        for (k, m) in matrices.iter().enumerate() {
+            assert!(m.rows() == rows, "hstack matrix {} has {} rows, expected {}", k, m.rows(), rows);
+        }
+
+        // the matrix and column each output column is copied from
+        let sources: Vec<(usize, usize)> = matrices.iter().enumerate()
+            .flat_map(|(k, m)| (0..m.cols()).map(move |j| (k, j)))
+            .collect();
+        Matrix::from_fn(rows, sources.len(), |i, j| {
+            let (k, j) = sources[j];
+            matrices[k][i][j].clone()
+        })
+    }
+    /// concatenates matrices with the same number of columns top to bottom, an empty slice
+    /// gives a 0x0 matrix
+    #[inline]
+    pub fn vstack(matrices: &[Matrix<T>]) -> Matrix<T> {
+        let cols = matrices.first().map_or(0, |m| m.cols());
+        for (k, m) in matrices.iter().enumerate() {
+            assert!(m.cols() == cols, "vstack matrix {} has {} columns, expected {}", k, m.cols(), cols);
+        }
+
+        // the matrix and row each output row is copied from
+        let sources: Vec<(usize, usize)> = matrices.iter().enumerate()
+            .flat_map(|(k, m)| (0..m.rows()).map(move |i| (k, i)))
+            .collect();
+        Matrix::from_fn(sources.len(), cols, |i, j| {
+            let (k, i) = sources[i];
+            matrices[k][i][j].clone()
+        })
+    }
+    /// assembles a matrix from a grid of blocks, e.g. `Matrix::block(&[[a, b], [c, d]])`,
+    /// blocks in a block row must have the same number of rows and blocks in a block column
+    /// the same number of columns
+    pub fn block<R: AsRef<[Matrix<T>]>>(blocks: &[R]) -> Matrix<T> {
+        let widths: Vec<usize> = match blocks.first() {
+            Some(row) => row.as_ref().iter().map(|m| m.cols()).collect(),
+            None => Vec::new(),
+        };
+
+        for (i, row) in blocks.iter().enumerate() {
+            let row = row.as_ref();
+            assert!(row.len() == widths.len(), "block row {} has {} blocks, expected {}", i, row.len(), widths.len());
+
+            for (j, m) in row.iter().enumerate() {
+                assert!(m.rows() == row[0].rows(),
+                    "block ({}, {}) has {} rows, expected {}", i, j, m.rows(), row[0].rows());
+                assert!(m.cols() == widths[j],
+                    "block ({}, {}) has {} columns, expected {}", i, j, m.cols(), widths[j]);
+            }
+        }
+
+        let rows: Vec<Matrix<T>> = blocks.iter().map(|row| Matrix::hstack(row.as_ref())).collect();
+        Matrix::vstack(&rows)
+    }
+
+    /// splits the matrix into column blocks with the given `widths`, the inverse of `hstack`
+    #[inline]
+    pub fn hsplit(&self, widths: &[usize]) -> Vec<Matrix<T>> {
+        assert_sizes_sum("hsplit", "widths", widths, self.cols());
+
+        let mut col = 0;
+        widths.iter().map(|&cols| {
+            col += cols;
+            submatrix(self, 0, col - cols, self.rows(), cols)
+        }).collect()
+    }
+    /// splits the matrix into row blocks with the given `heights`, the inverse of `vstack`
+    #[inline]
+    pub fn vsplit(&self, heights: &[usize]) -> Vec<Matrix<T>> {
+        assert_sizes_sum("vsplit", "heights", heights, self.rows());
+
+        let mut row = 0;
+        heights.iter().map(|&rows| {
+            row += rows;
+            submatrix(self, row - rows, 0, rows, self.cols())
+        }).collect()
+    }
+    /// splits the matrix into a grid of blocks with the given row `heights` and column
+    /// `widths`, the inverse of `block`
+    #[inline]
+    pub fn split_blocks(&self, heights: &[usize], widths: &[usize]) -> Vec<Vec<Matrix<T>>> {
+        assert_sizes_sum("split_blocks", "heights", heights, self.rows());
+        assert_sizes_sum("split_blocks", "widths", widths, self.cols());
+        self.vsplit(heights).into_iter().map(|row| row.hsplit(widths)).collect()
+    }
+}
+
+impl<T> Matrix<T>
+    where T: Zero + Clone,
+{
+    /// places square or rectangular blocks along the diagonal with zeros elsewhere
+    #[inline]
+    pub fn block_diag(matrices: &[Matrix<T>]) -> Matrix<T> {
+        let rows = matrices.iter().map(|m| m.rows()).sum();
+        let cols = matrices.iter().map(|m| m.cols()).sum();
+        let mut out = Matrix::zeroed(rows, cols);
+
+        let (mut row, mut col) = (0, 0);
+        for m in matrices {
+            for i in 0..m.rows() {
+                for j in 0..m.cols() {
+                    out[row + i][col + j] = m[i][j].clone();
+                }
+            }
+            row += m.rows();
+            col += m.cols();
+        }
+        out
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+
+    #[test]
+    fn test_hstack_vstack() {
+        let a: Matrix<i32> = Matrix::from([[1, 2], [3, 4]]);
+        let b: Matrix<i32> = Matrix::from([[5], [6]]);
+        let c: Matrix<i32> = Matrix::from([[7, 8]]);
+
+        let ab = Matrix::hstack(&[a.clone(), b.clone()]);
+        assert_eq!(ab, Matrix::from([[1, 2, 5], [3, 4, 6]]));
+        assert_eq!(Matrix::vstack(&[a.clone(), c.clone()]), Matrix::from([[1, 2], [3, 4], [7, 8]]));
+
+        assert_eq!(ab.hsplit(&[2, 1]), [a.clone(), b]);
+        assert_eq!(Matrix::hstack(&[a.clone(), Matrix::zeroed(2, 0)]), a);
+        assert_eq!(Matrix::<i32>::hstack(&[]), Matrix::zeroed(0, 0));
+        assert_eq!(Matrix::<i32>::vstack(&[]), Matrix::zeroed(0, 0));
+    }
+    #[test]
+    #[should_panic(expected = "hstack matrix 1 has 1 rows, expected 2")]
+    fn test_hstack_wrong_rows() {
+        Matrix::<i32>::hstack(&[Matrix::zeroed(2, 2), Matrix::zeroed(1, 2)]);
+    }
+    #[test]
+    #[should_panic(expected = "vstack matrix 2 has 3 columns, expected 2")]
+    fn test_vstack_wrong_cols() {
+        Matrix::<i32>::vstack(&[Matrix::zeroed(2, 2), Matrix::zeroed(1, 2), Matrix::zeroed(1, 3)]);
+    }
+    #[test]
+    fn test_block() {
+        // a KKT system [[H, A^T], [A, 0]]
+        let h: Matrix<f64> = Matrix::from([[2.0, 0.0], [0.0, 2.0]]);
+        let a: Matrix<f64> = Matrix::from([[1.0, 1.0]]);
+        let kkt = Matrix::block(&[[h.clone(), a.transpose()], [a.clone(), Matrix::zeroed(1, 1)]]);
+        assert_eq!(kkt, Matrix::from([[2.0, 0.0, 1.0], [0.0, 2.0, 1.0], [1.0, 1.0, 0.0]]));
+
+        let blocks = kkt.split_blocks(&[2, 1], &[2, 1]);
+        assert_eq!(blocks[0][0], h);
+        assert_eq!(blocks[1][0], a);
+        assert_eq!(Matrix::block(&blocks), kkt);
+
+        assert_eq!(Matrix::<f64>::block::<[Matrix<f64>; 0]>(&[]), Matrix::zeroed(0, 0));
+    }
+    #[test]
+    #[should_panic(expected = "block (1, 0) has 1 columns, expected 2")]
+    fn test_block_misaligned_columns() {
+        Matrix::<i32>::block(&[
+            [Matrix::zeroed(1, 2), Matrix::zeroed(1, 1)],
+            [Matrix::zeroed(1, 1), Matrix::zeroed(1, 2)],
+        ]);
+    }
+    #[test]
+    #[should_panic(expected = "block (0, 1) has 2 rows, expected 1")]
+    fn test_block_misaligned_rows() {
+        Matrix::<i32>::block(&[[Matrix::zeroed(1, 2), Matrix::zeroed(2, 1)]]);
+    }
+    #[test]
+    fn test_block_diag() {
+        let a: Matrix<i32> = Matrix::from([[1, 2], [3, 4]]);
+        let b: Matrix<i32> = Matrix::from([[5, 6, 7]]);
+        assert_eq!(Matrix::block_diag(&[a.clone(), b.clone()]), Matrix::from([
+            [1, 2, 0, 0, 0],
+            [3, 4, 0, 0, 0],
+            [0, 0, 5, 6, 7],
+        ]));
+        assert_eq!(Matrix::block_diag(&[a.clone(), Matrix::zeroed(0, 0)]), a);
+        assert_eq!(Matrix::<i32>::block_diag(&[]), Matrix::zeroed(0, 0));
+    }
+    #[test]
+    fn test_vsplit() {
+        let m: Matrix<i32> = Matrix::from_fn(4, 2, |i, j| (i * 2 + j) as i32);
+        let parts = m.vsplit(&[1, 0, 3]);
+        assert_eq!(parts[0], Matrix::from([[0, 1]]));
+        assert_eq!(parts[1], Matrix::zeroed(0, 2));
+        assert_eq!(Matrix::vstack(&parts), m);
+    }
+    #[test]
+    #[should_panic(expected = "hsplit widths sum to 3, expected 2")]
+    fn test_hsplit_wrong_widths() {
+        Matrix::<i32>::zeroed(2, 2).hsplit(&[1, 2]);
+    }
+    #[test]
+    #[should_panic(expected = "split_blocks widths sum to 3, expected 2")]
+    fn test_split_blocks_wrong_widths() {
+        // with no block rows the widths are never handed to hsplit
+        Matrix::<i32>::zeroed(0, 2).split_blocks(&[], &[1, 2]);
+    }
+}
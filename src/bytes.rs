@@ -0,0 +1,259 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::mem;
+use core::str;
+
+use alloc::vec::Vec;
+
+use bytemuck::{self, Pod};
+
+use super::matrix::Matrix;
+use super::view::MatrixView;
+
+
+const MAGIC: &[u8; 4] = b"LAMX";
+/// written in native byte order so readers can detect a mismatch
+const BYTE_ORDER: u32 = 0x0102_0304;
+
+/// length of the header before the elements, a multiple of every primitive's alignment
+const HEADER_LEN: usize = 32;
+/// bytes of the header holding the element dtype, padded with zeros
+const DTYPE_LEN: usize = 8;
+
+
+/// element types that can be written to and viewed from a byte buffer
+pub trait BytesElement: Pod {
+    /// the dtype written in the header, so buffers of another type of the same size are
+    /// rejected, e.g. `f4`, at most 8 bytes
+    const DTYPE: &'static str;
+}
+
+macro_rules! impl_bytes_element {
+    ($($T: ty => $dtype: expr),*) => (
+        $(impl BytesElement for $T {
+            const DTYPE: &'static str = $dtype;
+        })*
+    );
+}
+
+impl_bytes_element!(
+    f32 => "f4", f64 => "f8",
+    i8 => "i1", i16 => "i2", i32 => "i4", i64 => "i8",
+    u8 => "u1", u16 => "u2", u32 => "u4", u64 => "u8"
+);
+
+
+/// reasons a byte buffer can not be viewed as a matrix
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BytesError {
+    /// the buffer does not start with a matrix header, or its shape does not fit in `usize`
+    Header,
+    /// the buffer was written with the other byte order
+    ByteOrder,
+    /// the header was written for elements of another dtype
+    DataType {
+        expected: [u8; DTYPE_LEN],
+        found: [u8; DTYPE_LEN],
+    },
+    /// the elements are not aligned for the element type
+    Alignment,
+    /// the buffer length does not match the shape
+    Length {
+        expected: usize,
+        found: usize,
+    },
+    /// the byte length of the shape does not fit in `usize`
+    Overflow {
+        rows: usize,
+        cols: usize,
+    },
+}
+
+impl fmt::Display for BytesError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BytesError::Header => f.write_str("missing matrix header"),
+            BytesError::ByteOrder => f.write_str("matrix was written with the other byte order"),
+            BytesError::DataType { ref expected, ref found } =>
+                write!(f, "expected elements of dtype `{}`, found `{}`", dtype_name(expected), dtype_name(found)),
+            BytesError::Alignment => f.write_str("matrix elements are not aligned"),
+            BytesError::Length { expected, found } =>
+                write!(f, "expected {} bytes of elements, found {}", expected, found),
+            BytesError::Overflow { rows, cols } =>
+                write!(f, "the byte length of a {}x{} matrix overflows usize", rows, cols),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for BytesError {}
+
+
+#[inline]
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes[..8]);
+    u64::from_le_bytes(buf)
+}
+
+#[inline]
+fn dtype<T: BytesElement>() -> [u8; DTYPE_LEN] {
+    let mut dtype = [0u8; DTYPE_LEN];
+    dtype[..T::DTYPE.len()].copy_from_slice(T::DTYPE.as_bytes());
+    dtype
+}
+#[inline]
+fn dtype_name(dtype: &[u8; DTYPE_LEN]) -> &str {
+    let len = dtype.iter().position(|b| *b == 0).unwrap_or(DTYPE_LEN);
+    str::from_utf8(&dtype[..len]).unwrap_or("?")
+}
+
+/// the header followed by `data` in native byte order
+#[inline]
+fn to_bytes<'a, T, I>(rows: usize, cols: usize, data: I) -> Vec<u8>
+    where T: 'a + BytesElement,
+          I: Iterator<Item = &'a T>,
+{
+    let mut bytes = Vec::with_capacity(HEADER_LEN + rows * cols * mem::size_of::<T>());
+
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&BYTE_ORDER.to_ne_bytes());
+    bytes.extend_from_slice(&dtype::<T>());
+    bytes.extend_from_slice(&(rows as u64).to_le_bytes());
+    bytes.extend_from_slice(&(cols as u64).to_le_bytes());
+    for x in data {
+        bytes.extend_from_slice(bytemuck::bytes_of(x));
+    }
+    bytes
+}
+
+
+impl<T: BytesElement> Matrix<T> {
+    /// copies the matrix into the layout read by `MatrixView::from_bytes`, a 32 byte header of
+    /// shape and dtype followed by the elements in row-major, native byte order
+    #[inline]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        to_bytes(self.rows(), self.cols(), self.iter())
+    }
+}
+
+impl<'a, T: BytesElement> MatrixView<'a, T> {
+    /// views a buffer written by `to_bytes` without copying, `bytes` must be aligned for `T`,
+    /// as memory-mapped files and allocations of `T` are
+    #[inline]
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, BytesError> {
+        if bytes.len() < HEADER_LEN || &bytes[..4] != MAGIC {
+            return Err(BytesError::Header);
+        }
+
+        let mut order = [0u8; 4];
+        order.copy_from_slice(&bytes[4..8]);
+        if u32::from_ne_bytes(order) != BYTE_ORDER {
+            return Err(if u32::from_ne_bytes(order) == BYTE_ORDER.swap_bytes() {
+                BytesError::ByteOrder
+            } else {
+                BytesError::Header
+            });
+        }
+
+        let mut found = [0u8; DTYPE_LEN];
+        found.copy_from_slice(&bytes[8..8 + DTYPE_LEN]);
+        if found != dtype::<T>() {
+            return Err(BytesError::DataType {
+                expected: dtype::<T>(),
+                found: found,
+            });
+        }
+
+        let rows = usize::try_from(read_u64(&bytes[16..])).map_err(|_| BytesError::Header)?;
+        let cols = usize::try_from(read_u64(&bytes[24..])).map_err(|_| BytesError::Header)?;
+        MatrixView::from_raw_bytes(rows, cols, &bytes[HEADER_LEN..])
+    }
+
+    /// views headerless elements in row-major, native byte order as a `rows x cols` matrix
+    #[inline]
+    pub fn from_raw_bytes(rows: usize, cols: usize, bytes: &'a [u8]) -> Result<Self, BytesError> {
+        let found = bytes.len();
+
+        match rows.checked_mul(cols).and_then(|len| len.checked_mul(mem::size_of::<T>())) {
+            Some(expected) if expected == found => match bytemuck::try_cast_slice(bytes) {
+                Ok(data) => Ok(MatrixView::new(rows, cols, data)),
+                Err(_) => Err(BytesError::Alignment),
+            },
+            Some(expected) => Err(BytesError::Length { expected: expected, found: found }),
+            None => Err(BytesError::Overflow { rows: rows, cols: cols }),
+        }
+    }
+
+    /// the elements without a header, the layout read by `from_raw_bytes`
+    #[inline(always)]
+    pub fn as_raw_bytes(&self) -> &'a [u8] {
+        bytemuck::cast_slice(self.as_slice())
+    }
+    /// copies the view into the layout read by `from_bytes`
+    #[inline]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        to_bytes(self.rows(), self.cols(), self.iter())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use super::*;
+
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let m: Matrix<f32> = Matrix::from([[1.0, -2.0, 3.5], [4.0, 0.25, 6.0]]);
+        let buf = aligned(&m.to_bytes());
+        let bytes = &bytemuck::cast_slice::<u64, u8>(&buf)[..HEADER_LEN + 24];
+
+        let view = MatrixView::<f32>::from_bytes(bytes).unwrap();
+        assert_eq!(view, m);
+        assert_eq!(view.as_slice().as_ptr() as usize, bytes[HEADER_LEN..].as_ptr() as usize);
+        assert_eq!(view.as_raw_bytes(), &bytes[HEADER_LEN..]);
+        assert_eq!(view.to_bytes(), bytes);
+
+        // elements of the same size but another type are rejected
+        assert!(matches!(MatrixView::<i32>::from_bytes(bytes), Err(BytesError::DataType { .. })));
+
+        let empty: Matrix<f64> = Matrix::zeroed(0, 3);
+        let buf = aligned(&empty.to_bytes());
+        let view = MatrixView::<f64>::from_bytes(bytemuck::cast_slice(&buf)).unwrap();
+        assert_eq!((view.rows(), view.cols()), (0, 3));
+    }
+    #[test]
+    fn test_bytes_errors() {
+        let m: Matrix<f64> = Matrix::from([[1.0, 2.0]]);
+        let buf = aligned(&m.to_bytes());
+        let bytes = bytemuck::cast_slice::<u64, u8>(&buf);
+
+        assert_eq!(MatrixView::<i64>::from_bytes(bytes).unwrap_err().to_string(),
+            "expected elements of dtype `i8`, found `f8`");
+        assert!(matches!(MatrixView::<f32>::from_bytes(bytes), Err(BytesError::DataType { .. })));
+        assert_eq!(MatrixView::<f64>::from_bytes(&bytes[..bytes.len() - 8]),
+            Err(BytesError::Length { expected: 16, found: 8 }));
+        assert_eq!(MatrixView::<f64>::from_bytes(&bytes[1..]), Err(BytesError::Header));
+        assert_eq!(MatrixView::<f64>::from_raw_bytes(1, 1, &bytes[HEADER_LEN + 1..HEADER_LEN + 9]),
+            Err(BytesError::Alignment));
+        assert_eq!(MatrixView::<f64>::from_raw_bytes(usize::MAX / 8 + 1, 1, &[]),
+            Err(BytesError::Overflow { rows: usize::MAX / 8 + 1, cols: 1 }));
+
+        let mut swapped = bytes.to_vec();
+        swapped[4..8].reverse();
+        let swapped = aligned(&swapped);
+        assert_eq!(MatrixView::<f64>::from_bytes(bytemuck::cast_slice(&swapped)), Err(BytesError::ByteOrder));
+    }
+
+
+    /// copies `bytes` into an allocation aligned for any primitive
+    fn aligned(bytes: &[u8]) -> Vec<u64> {
+        let mut buf = alloc::vec![0u64; bytes.len().div_ceil(8)];
+        bytemuck::cast_slice_mut::<u64, u8>(&mut buf)[..bytes.len()].copy_from_slice(bytes);
+        buf
+    }
+}
@@ -0,0 +1,155 @@
+use core::array;
+use core::convert::TryFrom;
+
+use alloc::vec::Vec;
+
+use super::matrix::Matrix;
+use super::vector::Vector;
+
+
+impl<T, const N: usize> From<[T; N]> for Vector<T> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        IntoIterator::into_iter(array).collect()
+    }
+}
+
+/// fails, returning the vector, if its length is not `N`
+impl<T, const N: usize> TryFrom<Vector<T>> for [T; N] {
+    type Error = Vector<T>;
+
+    #[inline]
+    fn try_from(vector: Vector<T>) -> Result<Self, Self::Error> {
+        if vector.len() == N {
+            let mut iter = vector.into_iter();
+            Ok(array::from_fn(|_| iter.next().unwrap()))
+        } else {
+            Err(vector)
+        }
+    }
+}
+
+impl<'a, T: Clone> From<&'a [T]> for Vector<T> {
+    #[inline(always)]
+    fn from(slice: &'a [T]) -> Self {
+        Vector::from_slice(slice)
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Matrix<T> {
+    #[inline]
+    fn from(array: [[T; C]; R]) -> Self {
+        let mut iter = IntoIterator::into_iter(array).flat_map(IntoIterator::into_iter);
+        Matrix::from_fn(R, C, |_, _| iter.next().unwrap())
+    }
+}
+
+/// fails, returning the matrix, if its shape is not `R`x`C`
+impl<T, const R: usize, const C: usize> TryFrom<Matrix<T>> for [[T; C]; R] {
+    type Error = Matrix<T>;
+
+    #[inline]
+    fn try_from(matrix: Matrix<T>) -> Result<Self, Self::Error> {
+        if matrix.rows() == R && matrix.cols() == C {
+            let mut iter = matrix.into_iter();
+            Ok(array::from_fn(|_| array::from_fn(|_| iter.next().unwrap())))
+        } else {
+            Err(matrix)
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    #[inline(always)]
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T> From<Vector<T>> for Vec<T> {
+    #[inline(always)]
+    fn from(vector: Vector<T>) -> Self {
+        vector.into_iter().collect()
+    }
+}
+
+/// fails, returning the rows, if they differ in length
+impl<T> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = Vec<Vec<T>>;
+
+    #[inline]
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        if rows.iter().all(|row| row.len() == rows[0].len()) {
+            Ok(rows.into_iter().map(Vector::from).collect())
+        } else {
+            Err(rows)
+        }
+    }
+}
+
+impl<T> From<Matrix<T>> for Vec<Vec<T>> {
+    #[inline]
+    fn from(matrix: Matrix<T>) -> Self {
+        matrix.into_rows().into_iter().map(Vec::from).collect()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+
+    #[test]
+    fn test_vector_from_array() {
+        let v: Vector<usize> = Vector::from([1, 2, 3]);
+        assert_eq!(&*v, &[1, 2, 3]);
+
+        let a: [usize; 3] = <[usize; 3]>::try_from(v.clone()).unwrap();
+        assert_eq!(a, [1, 2, 3]);
+        assert_eq!(<[usize; 2]>::try_from(v.clone()), Err(v));
+    }
+    #[test]
+    fn test_vector_from_slice() {
+        let v: Vector<usize> = Vector::from(&[1, 2, 3][..]);
+        assert_eq!(&*v, &[1, 2, 3]);
+    }
+    #[test]
+    fn test_matrix_from_array() {
+        let m: Matrix<usize> = Matrix::from([[1, 0], [0, 1], [2, 3]]);
+        assert_eq!(m.rows(), 3);
+        assert_eq!(m.cols(), 2);
+        assert_eq!(m[2][1], 3);
+
+        let empty: Matrix<usize> = Matrix::from([[0usize; 4]; 0]);
+        assert_eq!(empty.rows(), 0);
+        assert_eq!(empty.cols(), 4);
+
+        let a: [[usize; 2]; 3] = <[[usize; 2]; 3]>::try_from(m.clone()).unwrap();
+        assert_eq!(a, [[1, 0], [0, 1], [2, 3]]);
+        assert_eq!(<[[usize; 3]; 2]>::try_from(m.clone()), Err(m));
+    }
+    #[test]
+    fn test_vector_vec() {
+        let v: Vector<usize> = Vector::from(alloc::vec![1, 2, 3]);
+        let w: Vec<usize> = v.clone().into();
+        assert_eq!(w, [1, 2, 3]);
+        assert_eq!(&*v, &*w);
+    }
+    #[test]
+    fn test_matrix_vec() {
+        let m: Matrix<usize> = Matrix::try_from(alloc::vec![alloc::vec![1, 2], alloc::vec![3, 4]]).unwrap();
+        assert_eq!(m, Matrix::from([[1, 2], [3, 4]]));
+
+        let rows: Vec<Vec<usize>> = m.into();
+        assert_eq!(rows, [[1, 2], [3, 4]]);
+    }
+    #[test]
+    fn test_matrix_vec_ragged() {
+        let ragged = alloc::vec![alloc::vec![1, 2], alloc::vec![3]];
+        assert_eq!(Matrix::<usize>::try_from(ragged.clone()), Err(ragged));
+
+        let empty: Vec<Vec<usize>> = Vec::new();
+        assert_eq!(Matrix::try_from(empty).map(|m| (m.rows(), m.cols())), Ok((0, 0)));
+    }
+}
@@ -0,0 +1,271 @@
+use core::fmt::{self, Write};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::matrix::Matrix;
+use super::vector::Vector;
+
+
+/// dimensions longer than this are elided in the middle when displayed
+const SUMMARY_THRESHOLD: usize = 20;
+/// number of leading and trailing items kept when a dimension is elided
+const EDGE_ITEMS: usize = 4;
+
+const ELLIPSIS: &str = "...";
+
+
+type FormatFn<T> = fn(&T, Option<usize>, &mut String) -> fmt::Result;
+
+#[inline]
+fn display<T: fmt::Display>(x: &T, precision: Option<usize>, out: &mut String) -> fmt::Result {
+    match precision {
+        Some(p) => write!(out, "{:.*}", p, x),
+        None => write!(out, "{}", x),
+    }
+}
+/// `display` for `{:#}`, giving floats with an integral value a decimal point so they stay
+/// float literals, integers ignore precision which tells the two apart
+#[inline]
+fn display_literal<T: fmt::Display>(x: &T, precision: Option<usize>, out: &mut String) -> fmt::Result {
+    display(x, precision, out)?;
+
+    if precision.is_none() && out.bytes().all(|b| b == b'-' || b.is_ascii_digit()) {
+        let mut probe = String::new();
+        write!(probe, "{:.1}", x)?;
+
+        if probe.len() == out.len() + 2 && probe.starts_with(out.as_str()) && probe.ends_with(".0") {
+            *out = probe;
+        }
+    }
+    Ok(())
+}
+#[inline]
+fn lower_exp<T: fmt::LowerExp>(x: &T, precision: Option<usize>, out: &mut String) -> fmt::Result {
+    match precision {
+        Some(p) => write!(out, "{:.*e}", p, x),
+        None => write!(out, "{:e}", x),
+    }
+}
+#[inline]
+fn upper_exp<T: fmt::UpperExp>(x: &T, precision: Option<usize>, out: &mut String) -> fmt::Result {
+    match precision {
+        Some(p) => write!(out, "{:.*E}", p, x),
+        None => write!(out, "{:E}", x),
+    }
+}
+
+/// returns the indices to print for a dimension of `len`, `None` marks the elided middle
+#[inline]
+fn summarize(len: usize, elide: bool) -> Vec<Option<usize>> {
+    if elide && len > SUMMARY_THRESHOLD {
+        (0..EDGE_ITEMS).map(Some)
+            .chain(Some(None))
+            .chain((len - EDGE_ITEMS..len).map(Some))
+            .collect()
+    } else {
+        (0..len).map(Some).collect()
+    }
+}
+
+#[inline]
+fn fmt_vector<T>(v: &Vector<T>, f: &mut fmt::Formatter, format: FormatFn<T>) -> fmt::Result {
+    let literal = f.alternate();
+    let mut cell = String::new();
+
+    f.write_str(if literal { "vector![" } else { "[" })?;
+    for (k, index) in summarize(v.len(), !literal).into_iter().enumerate() {
+        if k != 0 {
+            f.write_str(", ")?;
+        }
+        match index {
+            Some(i) => {
+                cell.clear();
+                format(&v[i], f.precision(), &mut cell)?;
+                f.write_str(&cell)?;
+            },
+            None => f.write_str(ELLIPSIS)?,
+        }
+    }
+    f.write_str("]")
+}
+
+#[inline]
+fn fmt_matrix<T>(m: &Matrix<T>, f: &mut fmt::Formatter, format: FormatFn<T>) -> fmt::Result {
+    let literal = f.alternate();
+    let rows = summarize(m.rows(), !literal);
+    let cols = summarize(m.cols(), !literal);
+
+    let mut cells: Vec<Vec<String>> = Vec::with_capacity(rows.len());
+    let mut widths: Vec<usize> = cols.iter().map(|_| 0).collect();
+
+    for &row in rows.iter() {
+        let mut line = Vec::with_capacity(cols.len());
+
+        for (k, &col) in cols.iter().enumerate() {
+            let mut cell = String::new();
+            match (row, col) {
+                (Some(i), Some(j)) => format(&m[i][j], f.precision(), &mut cell)?,
+                _ => cell.push_str(ELLIPSIS),
+            }
+            widths[k] = widths[k].max(cell.chars().count());
+            line.push(cell);
+        }
+        cells.push(line);
+    }
+
+    if literal && (m.rows() == 0 || m.cols() == 0) {
+        // `matrix!` needs at least one element, so an empty matrix prints its shape instead
+        write!(f, "Matrix::zeroed({}, {})", m.rows(), m.cols())
+    } else if literal {
+        f.write_str("matrix![")?;
+        for (i, line) in cells.iter().enumerate() {
+            f.write_str("\n    ")?;
+            for (k, cell) in line.iter().enumerate() {
+                if k != 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{:>1$}", cell, widths[k])?;
+            }
+            if i + 1 != cells.len() {
+                f.write_str(";")?;
+            }
+        }
+        f.write_str("\n]")
+    } else {
+        for (i, line) in cells.iter().enumerate() {
+            if i != 0 {
+                f.write_str("\n")?;
+            }
+            f.write_str("[")?;
+            for (k, cell) in line.iter().enumerate() {
+                if k != 0 {
+                    f.write_str(" ")?;
+                }
+                write!(f, "{:>1$}", cell, widths[k])?;
+            }
+            f.write_str("]")?;
+        }
+        Ok(())
+    }
+}
+
+
+/// prints one bracketed line per row with right-aligned columns, honouring precision and
+/// eliding the middle of dimensions longer than 20
+///
+/// `{:#}` prints a `matrix![..]` literal, with a decimal point on every float, and an empty
+/// matrix, which `matrix!` can not express, as `Matrix::zeroed(rows, cols)`
+impl<T: fmt::Display> fmt::Display for Matrix<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let format: FormatFn<T> = if f.alternate() { display_literal::<T> } else { display::<T> };
+        fmt_matrix(self, f, format)
+    }
+}
+impl<T: fmt::LowerExp> fmt::LowerExp for Matrix<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_matrix(self, f, lower_exp::<T>)
+    }
+}
+impl<T: fmt::UpperExp> fmt::UpperExp for Matrix<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_matrix(self, f, upper_exp::<T>)
+    }
+}
+
+/// prints a bracketed list honouring precision and eliding the middle of vectors longer
+/// than 20
+///
+/// `{:#}` prints a `vector![..]` literal, with a decimal point on every float
+impl<T: fmt::Display> fmt::Display for Vector<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let format: FormatFn<T> = if f.alternate() { display_literal::<T> } else { display::<T> };
+        fmt_vector(self, f, format)
+    }
+}
+impl<T: fmt::LowerExp> fmt::LowerExp for Vector<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_vector(self, f, lower_exp::<T>)
+    }
+}
+impl<T: fmt::UpperExp> fmt::UpperExp for Vector<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_vector(self, f, upper_exp::<T>)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use alloc::format;
+
+    use super::*;
+
+
+    #[test]
+    fn test_display_matrix() {
+        let m: Matrix<f64> = Matrix::from([[1.0, -2.5], [30.25, 4.0]]);
+        assert_eq!(format!("{}", m), "[    1 -2.5]\n[30.25    4]");
+        assert_eq!(format!("{:.2}", m), "[ 1.00 -2.50]\n[30.25  4.00]");
+    }
+    #[test]
+    fn test_display_matrix_unicode() {
+        // columns are padded by chars, not bytes
+        let m: Matrix<&str> = Matrix::from([["π", "ab"], ["xyz", "é"]]);
+        assert_eq!(format!("{}", m), "[  π ab]\n[xyz  é]");
+    }
+    #[test]
+    fn test_display_matrix_exp() {
+        let m: Matrix<f64> = Matrix::from([[1500.0, 0.25]]);
+        assert_eq!(format!("{:e}", m), "[1.5e3 2.5e-1]");
+        assert_eq!(format!("{:.1E}", m), "[1.5E3 2.5E-1]");
+    }
+    #[test]
+    fn test_display_matrix_literal() {
+        let m: Matrix<i32> = Matrix::from([[1, -20], [300, 4]]);
+        assert_eq!(format!("{:#}", m), "matrix![\n      1, -20;\n    300,   4\n]");
+
+        let f: Matrix<f64> = Matrix::from([[1.0, 2.5], [-300.0, 1e20]]);
+        assert_eq!(format!("{:#}", f), "matrix![\n       1.0,                     2.5;\n    -300.0, 100000000000000000000.0\n]");
+        assert_eq!(format!("{:#.2}", Matrix::from([[1.0f32]])), "matrix![\n    1.00\n]");
+        assert_eq!(format!("{:#e}", Matrix::from([[1.0, 2.5]])), "matrix![\n    1e0, 2.5e0\n]");
+    }
+    #[test]
+    fn test_display_matrix_elided() {
+        let m: Matrix<usize> = Matrix::from_fn(30, 25, |i, j| i * 100 + j);
+        let s = format!("{}", m);
+        let lines: Vec<&str> = s.lines().collect();
+
+        assert_eq!(lines.len(), 2 * EDGE_ITEMS + 1);
+        assert_eq!(lines[0], "[   0    1    2    3 ...   21   22   23   24]");
+        assert_eq!(lines[EDGE_ITEMS], "[ ...  ...  ...  ... ...  ...  ...  ...  ...]");
+        assert_eq!(lines[2 * EDGE_ITEMS], "[2900 2901 2902 2903 ... 2921 2922 2923 2924]");
+    }
+    #[test]
+    fn test_display_vector() {
+        let v: Vector<f32> = Vector::from([1.0, 0.5]);
+        assert_eq!(format!("{}", v), "[1, 0.5]");
+        assert_eq!(format!("{:.3}", v), "[1.000, 0.500]");
+        assert_eq!(format!("{:#}", v), "vector![1.0, 0.5]");
+        assert_eq!(format!("{:#.1}", v), "vector![1.0, 0.5]");
+        assert_eq!(format!("{:#}", Vector::from([-2, 10])), "vector![-2, 10]");
+        assert_eq!(format!("{:e}", v), "[1e0, 5e-1]");
+
+        let long: Vector<usize> = (0..100).collect();
+        assert_eq!(format!("{}", long), "[0, 1, 2, 3, ..., 96, 97, 98, 99]");
+    }
+    #[test]
+    fn test_display_empty() {
+        let m: Matrix<usize> = Matrix::zeroed(0, 3);
+        assert_eq!(format!("{}", m), "");
+        assert_eq!(format!("{:#}", m), "Matrix::zeroed(0, 3)");
+        assert_eq!(format!("{:#}", Matrix::<usize>::zeroed(2, 0)), "Matrix::zeroed(2, 0)");
+        assert_eq!(format!("{:#}", Vector::<usize>::new(0)), "vector![]");
+    }
+}
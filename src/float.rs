@@ -0,0 +1,84 @@
+use core::ops::*;
+
+use libm;
+use one::One;
+use zero::Zero;
+
+
+/// floating point element types, the math is done by `libm` so it is available without `std`
+pub trait Float: Copy + PartialOrd + Zero + One +
+                 Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> +
+                 Neg<Output = Self> + AddAssign + SubAssign + MulAssign + DivAssign
+{
+    fn epsilon() -> Self;
+    fn infinity() -> Self;
+    fn nan() -> Self;
+    /// converts with rounding, for constants and counts
+    fn from_f64(x: f64) -> Self;
+
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    /// `sqrt(self * self + other * other)` without intermediate overflow
+    fn hypot(self, other: Self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($T: ident, $EPSILON: expr, $abs: ident, $sqrt: ident, $hypot: ident, $pow: ident, $exp: ident,
+     $log: ident, $sin: ident, $cos: ident, $tan: ident, $asin: ident, $acos: ident, $atan2: ident) => (
+        impl Float for $T {
+            #[inline(always)]
+            fn epsilon() -> Self { $EPSILON }
+            #[inline(always)]
+            fn infinity() -> Self { $T::INFINITY }
+            #[inline(always)]
+            fn nan() -> Self { $T::NAN }
+            #[inline(always)]
+            fn from_f64(x: f64) -> Self { x as $T }
+
+            #[inline(always)]
+            fn is_nan(self) -> bool { $T::is_nan(self) }
+            #[inline(always)]
+            fn is_finite(self) -> bool { $T::is_finite(self) }
+
+            #[inline(always)]
+            fn abs(self) -> Self { libm::$abs(self) }
+            #[inline(always)]
+            fn sqrt(self) -> Self { libm::$sqrt(self) }
+            #[inline(always)]
+            fn hypot(self, other: Self) -> Self { libm::$hypot(self, other) }
+            #[inline(always)]
+            fn powf(self, n: Self) -> Self { libm::$pow(self, n) }
+            #[inline(always)]
+            fn exp(self) -> Self { libm::$exp(self) }
+            #[inline(always)]
+            fn ln(self) -> Self { libm::$log(self) }
+            #[inline(always)]
+            fn sin(self) -> Self { libm::$sin(self) }
+            #[inline(always)]
+            fn cos(self) -> Self { libm::$cos(self) }
+            #[inline(always)]
+            fn tan(self) -> Self { libm::$tan(self) }
+            #[inline(always)]
+            fn asin(self) -> Self { libm::$asin(self) }
+            #[inline(always)]
+            fn acos(self) -> Self { libm::$acos(self) }
+            #[inline(always)]
+            fn atan2(self, other: Self) -> Self { libm::$atan2(self, other) }
+        }
+    );
+}
+
+impl_float!(f32, f32::EPSILON, fabsf, sqrtf, hypotf, powf, expf, logf, sinf, cosf, tanf, asinf, acosf, atan2f);
+impl_float!(f64, f64::EPSILON, fabs, sqrt, hypot, pow, exp, log, sin, cos, tan, asin, acos, atan2);
@@ -0,0 +1,219 @@
+use alloc::vec::Vec;
+
+use super::float::Float;
+use super::vector::Vector;
+
+
+#[inline]
+fn assert_same_len<T>(op: &str, a: &Vector<T>, b: &Vector<T>) {
+    assert!(a.len() == b.len(), "{} requires vectors of the same length, found {} and {}", op, a.len(), b.len());
+}
+
+/// `a . b` by the shared dot product kernel, which needs no more of `T` than `Float`
+#[inline]
+fn dot<T: Float>(a: &Vector<T>, b: &Vector<T>) -> T {
+    a.dot_with(b, |x, y| *x * *y)
+}
+
+
+impl<T: Float> Vector<T> {
+    /// the cross product of two 3-dimensional vectors
+    #[inline]
+    pub fn cross(&self, other: &Vector<T>) -> Vector<T> {
+        assert!(self.len() == 3 && other.len() == 3,
+            "cross requires 3-dimensional vectors, found lengths {} and {}", self.len(), other.len());
+
+        Vector::from([
+            self[1] * other[2] - self[2] * other[1],
+            self[2] * other[0] - self[0] * other[2],
+            self[0] * other[1] - self[1] * other[0],
+        ])
+    }
+    /// the z component of the cross product of two 2-dimensional vectors, positive if `other`
+    /// is counter-clockwise from `self`
+    #[inline]
+    pub fn perp_dot(&self, other: &Vector<T>) -> T {
+        assert!(self.len() == 2 && other.len() == 2,
+            "perp_dot requires 2-dimensional vectors, found lengths {} and {}", self.len(), other.len());
+
+        self[0] * other[1] - self[1] * other[0]
+    }
+
+    /// the angle in radians between two vectors, or `None` if either has zero length
+    ///
+    /// uses `2 atan2(|a/|a| - b/|b||, |a/|a| + b/|b||)` which stays accurate for nearly
+    /// parallel and nearly opposite vectors
+    #[inline]
+    pub fn angle_between(&self, other: &Vector<T>) -> Option<T> {
+        assert_same_len("angle_between", self, other);
+
+        match (self.normalize(), other.normalize()) {
+            (Some(a), Some(b)) => {
+                let diff = a.distance(&b);
+                let sum = a.iter().zip(b.iter()).map(|(x, y)| *x + *y).collect::<Vector<T>>().norm_l2();
+                Some((T::one() + T::one()) * diff.atan2(sum))
+            },
+            _ => None,
+        }
+    }
+
+    /// linearly interpolates from `self` at `t = 0` to `other` at `t = 1`
+    #[inline]
+    pub fn lerp(&self, other: &Vector<T>, t: T) -> Vector<T> {
+        assert_same_len("lerp", self, other);
+        self.zip_map(other, |a, b| *a + (*b - *a) * t)
+    }
+    /// spherically interpolates from `self` at `t = 0` to `other` at `t = 1` along the arc
+    /// between them, unit vectors stay unit length
+    ///
+    /// falls back to `lerp` when the vectors are nearly parallel or either has zero length,
+    /// nearly opposite vectors have no unique arc
+    #[inline]
+    pub fn slerp(&self, other: &Vector<T>, t: T) -> Vector<T> {
+        assert_same_len("slerp", self, other);
+
+        match self.angle_between(other) {
+            Some(angle) if angle.sin() > T::epsilon().sqrt() => {
+                let sin = angle.sin();
+                let a = ((T::one() - t) * angle).sin() / sin;
+                let b = (t * angle).sin() / sin;
+                self.zip_map(other, |x, y| *x * a + *y * b)
+            },
+            _ => self.lerp(other, t),
+        }
+    }
+
+    /// the component of `self` parallel to `onto`, zero if `onto` has zero length
+    #[inline]
+    pub fn project_onto(&self, onto: &Vector<T>) -> Vector<T> {
+        assert_same_len("project_onto", self, onto);
+
+        let len_sq = dot(onto, onto);
+        if len_sq == T::zero() {
+            onto.map(|_| T::zero())
+        } else {
+            let scale = dot(self, onto) / len_sq;
+            onto.map(|x| *x * scale)
+        }
+    }
+    /// the component of `self` perpendicular to `from`
+    #[inline]
+    pub fn reject_from(&self, from: &Vector<T>) -> Vector<T> {
+        let projection = self.project_onto(from);
+        self.zip_map(&projection, |x, p| *x - *p)
+    }
+    /// reflects `self` in the hyperplane with the given `normal`, which need not be unit length
+    #[inline]
+    pub fn reflect(&self, normal: &Vector<T>) -> Vector<T> {
+        let projection = self.project_onto(normal);
+        self.zip_map(&projection, |x, p| *x - (*p + *p))
+    }
+
+    /// orthonormalizes `vectors` with modified Gram-Schmidt, dropping any vector that is
+    /// linearly dependent on the ones before it
+    pub fn gram_schmidt(vectors: &[Vector<T>]) -> Vec<Vector<T>> {
+        let mut basis: Vec<Vector<T>> = Vec::with_capacity(vectors.len());
+
+        for v in vectors {
+            assert_same_len("gram_schmidt", &vectors[0], v);
+
+            let mut u = v.clone();
+            for b in basis.iter() {
+                let d = dot(&u, b);
+                u.zip_apply(b, |x, y| *x -= d * *y);
+            }
+
+            // all that is left of a dependent vector is rounding error
+            let tolerance = v.norm_l2() * T::epsilon() * T::from_f64(v.len() as f64);
+            if u.norm_l2() > tolerance {
+                if let Some(u) = u.normalize() {
+                    basis.push(u);
+                }
+            }
+        }
+
+        basis
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+
+    #[test]
+    fn test_cross_perp_dot() {
+        let x: Vector<f64> = Vector::from([1.0, 0.0, 0.0]);
+        let y: Vector<f64> = Vector::from([0.0, 1.0, 0.0]);
+        assert_eq!(x.cross(&y), Vector::from([0.0, 0.0, 1.0]));
+        assert_eq!(y.cross(&x), Vector::from([0.0, 0.0, -1.0]));
+
+        let a: Vector<f32> = Vector::from([1.0, 0.0]);
+        assert_eq!(a.perp_dot(&Vector::from([0.0, 2.0])), 2.0);
+        assert_eq!(a.perp_dot(&Vector::from([0.0, -2.0])), -2.0);
+    }
+    #[test]
+    #[should_panic]
+    fn test_cross_wrong_len() {
+        Vector::<f64>::from([1.0, 0.0]).cross(&Vector::from([0.0, 1.0]));
+    }
+    #[test]
+    fn test_angle_between() {
+        let x: Vector<f64> = Vector::from([2.0, 0.0]);
+        let pi = core::f64::consts::PI;
+        assert!((x.angle_between(&Vector::from([1.0, 1.0])).unwrap() - pi / 4.0).abs() < 1e-15);
+        assert!((x.angle_between(&Vector::from([-3.0, 0.0])).unwrap() - pi).abs() < 1e-15);
+        assert!(x.angle_between(&Vector::from([1.0, 1e-9])).unwrap() > 0.0);
+        assert_eq!(x.angle_between(&Vector::from([0.0, 0.0])), None);
+    }
+    #[test]
+    fn test_project_reject_reflect() {
+        let v: Vector<f64> = Vector::from([3.0, 4.0]);
+        let x: Vector<f64> = Vector::from([2.0, 0.0]);
+        assert_eq!(v.project_onto(&x), Vector::from([3.0, 0.0]));
+        assert_eq!(v.reject_from(&x), Vector::from([0.0, 4.0]));
+        assert_eq!(v.reflect(&Vector::from([0.0, 1.0])), Vector::from([3.0, -4.0]));
+        assert_eq!(v.project_onto(&Vector::from([0.0, 0.0])), Vector::from([0.0, 0.0]));
+    }
+    #[test]
+    fn test_lerp_slerp() {
+        let x: Vector<f64> = Vector::from([1.0, 0.0]);
+        let y: Vector<f64> = Vector::from([0.0, 1.0]);
+        assert_eq!(x.lerp(&y, 0.5), Vector::from([0.5, 0.5]));
+
+        let half = x.slerp(&y, 0.5);
+        let expected = 0.5f64.sqrt();
+        assert!((half[0] - expected).abs() < 1e-15 && (half[1] - expected).abs() < 1e-15);
+        assert_eq!(x.slerp(&y, 0.0), x);
+        assert!((x.slerp(&y, 1.0).distance(&y)) < 1e-15);
+        assert_eq!(x.slerp(&x, 0.3), x);
+    }
+    #[test]
+    fn test_gram_schmidt() {
+        let vectors: [Vector<f64>; 4] = [
+            Vector::from([1.0, 1.0, 0.0]),
+            Vector::from([2.0, 2.0, 0.0]),
+            Vector::from([1.0, 0.0, 1.0]),
+            Vector::from([0.0, 0.0, 0.0]),
+        ];
+        let basis = Vector::gram_schmidt(&vectors);
+
+        assert_eq!(basis.len(), 2);
+        for (i, a) in basis.iter().enumerate() {
+            assert!((a.norm_l2() - 1.0).abs() < 1e-15);
+            for b in basis[i + 1..].iter() {
+                assert!(a.dot(b).abs() < 1e-15);
+            }
+        }
+    }
+    #[test]
+    fn test_float_bound_only() {
+        fn reflected<T: Float>(v: &Vector<T>, n: &Vector<T>) -> (Vector<T>, Vec<Vector<T>>) {
+            (v.reflect(n), Vector::gram_schmidt(&[v.clone(), n.clone()]))
+        }
+        let (r, basis) = reflected(&Vector::<f64>::from([1.0, -1.0]), &Vector::from([0.0, 2.0]));
+        assert_eq!(r, Vector::from([1.0, 1.0]));
+        assert_eq!(basis.len(), 2);
+    }
+}
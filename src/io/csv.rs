@@ -0,0 +1,295 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use std::format;
+use std::io::{BufRead, Write};
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+use super::super::matrix::Matrix;
+use super::super::vector::Vector;
+use super::IoError;
+
+
+/// options for reading and writing delimited text, one matrix row per record
+///
+/// parse errors report the 1-based line of the record and the 1-based field as the column
+#[derive(Clone, Debug)]
+pub struct CsvOptions {
+    delimiter: char,
+    quote: char,
+    comment: Option<char>,
+    has_header: bool,
+    nan_tokens: Vec<String>,
+}
+
+impl Default for CsvOptions {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CsvOptions {
+    /// comma delimited, `"` quoted, without comments, header or NaN tokens
+    #[inline]
+    pub fn new() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            quote: '"',
+            comment: None,
+            has_header: false,
+            nan_tokens: Vec::new(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+    #[inline(always)]
+    pub fn quote(mut self, quote: char) -> Self {
+        self.quote = quote;
+        self
+    }
+    /// skips lines starting with `comment`
+    #[inline(always)]
+    pub fn comment(mut self, comment: char) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+    /// skips the first record when reading
+    #[inline(always)]
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+    /// reads fields equal to `token` as `NaN`
+    #[inline]
+    pub fn nan_token<S: Into<String>>(mut self, token: S) -> Self {
+        self.nan_tokens.push(token.into());
+        self
+    }
+
+    /// splits `record` into fields, returns `None` if it ends inside a quoted field
+    #[inline]
+    fn split(&self, record: &str) -> Option<Vec<String>> {
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut in_quotes = false;
+        let mut chars = record.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if in_quotes {
+                if c != self.quote {
+                    field.push(c);
+                } else if chars.peek() == Some(&self.quote) {
+                    field.push(c);
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else if c == self.delimiter {
+                fields.push(if quoted { field } else { field.trim().into() });
+                field = String::new();
+                quoted = false;
+            } else if c == self.quote && !quoted && field.trim().is_empty() {
+                field.clear();
+                quoted = true;
+                in_quotes = true;
+            } else if !(quoted && c.is_whitespace()) {
+                field.push(c);
+            }
+        }
+
+        if in_quotes {
+            None
+        } else {
+            fields.push(if quoted { field } else { field.trim().into() });
+            Some(fields)
+        }
+    }
+
+    #[inline]
+    fn parse<T: FromStr>(&self, line: usize, column: usize, field: &str) -> Result<T, IoError> {
+        let token = if self.nan_tokens.iter().any(|token| token == field) { "NaN" } else { field };
+
+        token.parse().map_err(|_| IoError::parse(line, Some(column), format!("invalid value `{}`", field)))
+    }
+
+    /// reads every record into a row of a `Matrix`, skipping blank and comment lines, quoted
+    /// fields may contain delimiters, newlines and doubled quotes
+    pub fn read<T, R>(&self, reader: R) -> Result<Matrix<T>, IoError>
+        where T: FromStr,
+              R: BufRead,
+    {
+        let mut lines = reader.lines().enumerate().map(|(i, line)| (i + 1, line));
+        let mut rows: Vec<Vector<T>> = Vec::new();
+        let mut cols = None;
+        let mut skip_header = self.has_header;
+
+        while let Some((n, line)) = lines.next() {
+            let mut record = line?;
+            let trimmed = record.trim_start();
+
+            if trimmed.is_empty() || self.comment.is_some_and(|c| trimmed.starts_with(c)) {
+                continue;
+            }
+
+            let fields = loop {
+                match self.split(&record) {
+                    Some(fields) => break fields,
+                    None => match lines.next() {
+                        Some((_, line)) => {
+                            record.push('\n');
+                            record.push_str(&line?);
+                        },
+                        None => return Err(IoError::parse(n, None, "unterminated quoted field")),
+                    },
+                }
+            };
+
+            if skip_header {
+                skip_header = false;
+                continue;
+            }
+            match cols {
+                Some(cols) if cols != fields.len() => {
+                    return Err(IoError::parse(n, None, format!("row has {} fields, expected {}", fields.len(), cols)));
+                },
+                _ => cols = Some(fields.len()),
+            }
+
+            let row = fields.iter().enumerate()
+                .map(|(j, field)| self.parse(n, j + 1, field))
+                .collect::<Result<Vector<T>, IoError>>()?;
+            rows.push(row);
+        }
+
+        Ok(rows.into_iter().collect())
+    }
+
+    /// writes each row of `matrix` as a record, quoting fields that need it, fails on a
+    /// matrix with rows but no columns, whose empty records would read back as no rows
+    pub fn write<T, W>(&self, mut writer: W, matrix: &Matrix<T>) -> Result<(), IoError>
+        where T: Display,
+              W: Write,
+    {
+        if matrix.cols() == 0 && matrix.rows() != 0 {
+            return Err(IoError::Unsupported(format!("csv for a {}x0 matrix", matrix.rows())));
+        }
+        let mut record = String::new();
+
+        for row in matrix.row_iter() {
+            record.clear();
+
+            for (j, x) in row.iter().enumerate() {
+                if j != 0 {
+                    record.push(self.delimiter);
+                }
+
+                let field = x.to_string();
+                let needs_quotes = field.is_empty() || field.trim() != field ||
+                    field.contains(&[self.delimiter, self.quote, '\n', '\r'][..]) ||
+                    self.comment.is_some_and(|c| field.starts_with(c));
+
+                if needs_quotes {
+                    record.push(self.quote);
+                    for c in field.chars() {
+                        if c == self.quote {
+                            record.push(c);
+                        }
+                        record.push(c);
+                    }
+                    record.push(self.quote);
+                } else {
+                    record.push_str(&field);
+                }
+            }
+            record.push('\n');
+            writer.write_all(record.as_bytes())?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+
+    #[test]
+    fn test_read_csv() {
+        let file = "x,y,z\n# a comment\n1, 2.5 ,-3\n\n4,5,6\n";
+        let m: Matrix<f64> = CsvOptions::new().has_header(true).comment('#').read(file.as_bytes()).unwrap();
+        assert_eq!(m, Matrix::from([[1.0, 2.5, -3.0], [4.0, 5.0, 6.0]]));
+
+        let m: Matrix<i32> = CsvOptions::new().delimiter('\t').read("1\t2\n3\t4".as_bytes()).unwrap();
+        assert_eq!(m, Matrix::from([[1, 2], [3, 4]]));
+
+        let m: Matrix<f64> = CsvOptions::new().read("".as_bytes()).unwrap();
+        assert!(m.is_empty());
+    }
+    #[test]
+    fn test_read_csv_nan() {
+        let m: Matrix<f64> = CsvOptions::new().nan_token("NA").nan_token("").read("1,NA\n,nan\n".as_bytes()).unwrap();
+        assert_eq!(m[0][0], 1.0);
+        assert!(m[0][1].is_nan() && m[1][0].is_nan() && m[1][1].is_nan());
+    }
+    #[test]
+    fn test_read_csv_quoted() {
+        let file = "\"a, \"\"b\"\"\", c\n\"multi\nline\" ,d\n";
+        let m: Matrix<String> = CsvOptions::new().read(file.as_bytes()).unwrap();
+        assert_eq!(m[0][0], "a, \"b\"");
+        assert_eq!(m[0][1], "c");
+        assert_eq!(m[1][0], "multi\nline");
+
+        let err = CsvOptions::new().read::<String, _>("\"open,1\n2,3\n".as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "line 1: unterminated quoted field");
+    }
+    #[test]
+    fn test_read_csv_errors() {
+        let err = CsvOptions::new().read::<f64, _>("1,2\n3,x\n".as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 2: invalid value `x`");
+
+        let err = CsvOptions::new().comment('%').read::<f64, _>("% c\n1,2\n3\n".as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "line 3: row has 1 fields, expected 2");
+    }
+    #[test]
+    fn test_write_csv() {
+        let m: Matrix<f64> = Matrix::from([[1.0, -2.5], [0.125, 4.0]]);
+        let mut out = Vec::new();
+        CsvOptions::new().delimiter(';').write(&mut out, &m).unwrap();
+        assert_eq!(String::from_utf8(out.clone()).unwrap(), "1;-2.5\n0.125;4\n");
+        assert_eq!(CsvOptions::new().delimiter(';').read::<f64, _>(&*out).unwrap(), m);
+
+        let m: Matrix<String> = Matrix::from([["a,b".to_string(), "say \"hi\"".to_string(), " x".to_string()]]);
+        let mut out = Vec::new();
+        CsvOptions::new().write(&mut out, &m).unwrap();
+        assert_eq!(String::from_utf8(out.clone()).unwrap(), "\"a,b\",\"say \"\"hi\"\"\",\" x\"\n");
+        assert_eq!(CsvOptions::new().read::<String, _>(&*out).unwrap(), m);
+    }
+    #[test]
+    fn test_write_csv_comment() {
+        // a field starting with the comment char is quoted so its record is not skipped
+        let options = CsvOptions::new().comment('#');
+        let m: Matrix<String> = Matrix::from([["#x".to_string(), "y#".to_string()], ["1".to_string(), "2".to_string()]]);
+        let mut out = Vec::new();
+        options.write(&mut out, &m).unwrap();
+        assert_eq!(String::from_utf8(out.clone()).unwrap(), "\"#x\",y#\n1,2\n");
+        assert_eq!(options.read::<String, _>(&*out).unwrap(), m);
+    }
+    #[test]
+    fn test_write_csv_no_columns() {
+        let err = CsvOptions::new().write(Vec::new(), &Matrix::<f64>::zeroed(3, 0)).unwrap_err();
+        assert_eq!(err.to_string(), "unsupported: csv for a 3x0 matrix");
+
+        let mut out = Vec::new();
+        CsvOptions::new().write(&mut out, &Matrix::<f64>::zeroed(0, 3)).unwrap();
+        assert!(out.is_empty());
+    }
+}
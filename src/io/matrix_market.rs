@@ -0,0 +1,371 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use std::format;
+use std::io::{BufRead, Write};
+use std::string::String;
+use std::vec::Vec;
+
+use one::One;
+use zero::Zero;
+
+use super::super::matrix::Matrix;
+use super::IoError;
+
+
+/// how the entries of a Matrix Market file are laid out
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatrixMarketFormat {
+    /// every entry in column-major order
+    Array,
+    /// only the non-zero entries, each with its 1-based row and column
+    Coordinate,
+}
+
+/// element types that can be written to a Matrix Market file
+pub trait MatrixMarketScalar: FromStr + Display {
+    /// the field name written in the header, `real` or `integer`
+    const FIELD: &'static str;
+}
+
+macro_rules! impl_matrix_market_scalar {
+    ($field: expr, $($T: ty),*) => (
+        $(impl MatrixMarketScalar for $T {
+            const FIELD: &'static str = $field;
+        })*
+    );
+}
+
+impl_matrix_market_scalar!("real", f32, f64);
+impl_matrix_market_scalar!("integer", i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+
+#[derive(Clone, Copy, PartialEq)]
+enum Field {
+    Real,
+    Integer,
+    Pattern,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Symmetry {
+    General,
+    Symmetric,
+}
+
+/// splits `line` on whitespace, yielding each token with its 1-based column
+#[inline]
+fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    let start = line.as_ptr() as usize;
+    line.split_whitespace().map(move |token| (token.as_ptr() as usize - start + 1, token))
+}
+
+#[inline]
+fn parse_token<T: FromStr>(line: usize, token: Option<(usize, &str)>, what: &str) -> Result<T, IoError> {
+    match token {
+        Some((column, token)) => token.parse().map_err(|_| {
+            IoError::parse(line, Some(column), format!("invalid {} `{}`", what, token))
+        }),
+        None => Err(IoError::parse(line, None, format!("missing {}", what))),
+    }
+}
+
+#[inline]
+fn parse_index(line: usize, token: Option<(usize, &str)>, what: &str, len: usize) -> Result<usize, IoError> {
+    let column = token.map(|(column, _)| column);
+    let index: usize = parse_token(line, token, what)?;
+
+    if index == 0 || index > len {
+        Err(IoError::parse(line, column, format!("{} {} out of range 1..={}", what, index, len)))
+    } else {
+        Ok(index - 1)
+    }
+}
+
+/// returns the next line that is neither blank nor a `%` comment
+#[inline]
+fn next_data_line<I>(lines: &mut I) -> Result<Option<(usize, String)>, IoError>
+    where I: Iterator<Item = (usize, Result<String, ::std::io::Error>)>,
+{
+    for (n, line) in lines {
+        let line = line?;
+        let trimmed = line.trim();
+
+        if !trimmed.is_empty() && !trimmed.starts_with('%') {
+            return Ok(Some((n, line)));
+        }
+    }
+    Ok(None)
+}
+
+#[inline]
+fn expect_end(line: usize, mut rest: impl Iterator<Item = (usize, impl Sized)>) -> Result<(), IoError> {
+    match rest.next() {
+        Some((column, _)) => Err(IoError::parse(line, Some(column), "unexpected trailing value")),
+        None => Ok(()),
+    }
+}
+
+/// reads a `Matrix` from a Matrix Market (`.mtx`) file in `array` or `coordinate` format with a
+/// `real`, `integer` or `pattern` field and `general` or `symmetric` symmetry
+pub fn read_matrix_market<T, R>(reader: R) -> Result<Matrix<T>, IoError>
+    where T: FromStr + Zero + One + Clone,
+          R: BufRead,
+{
+    let mut lines = reader.lines().enumerate().map(|(i, line)| (i + 1, line));
+
+    let header = match lines.next() {
+        Some((_, line)) => line?,
+        None => return Err(IoError::parse(1, None, "missing %%MatrixMarket header")),
+    };
+    let words: Vec<String> = header.split_whitespace().map(|word| word.to_lowercase()).collect();
+
+    if words.len() != 5 || words[0] != "%%matrixmarket" {
+        return Err(IoError::parse(1, None, "expected `%%MatrixMarket matrix <format> <field> <symmetry>`"));
+    }
+    if words[1] != "matrix" {
+        return Err(IoError::Unsupported(format!("object `{}`", words[1])));
+    }
+    let format = match &*words[2] {
+        "array" => MatrixMarketFormat::Array,
+        "coordinate" => MatrixMarketFormat::Coordinate,
+        other => return Err(IoError::Unsupported(format!("format `{}`", other))),
+    };
+    let field = match &*words[3] {
+        "real" | "double" => Field::Real,
+        "integer" => Field::Integer,
+        "pattern" => Field::Pattern,
+        other => return Err(IoError::Unsupported(format!("field `{}`", other))),
+    };
+    let symmetry = match &*words[4] {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        other => return Err(IoError::Unsupported(format!("symmetry `{}`", other))),
+    };
+    if field == Field::Pattern && format == MatrixMarketFormat::Array {
+        return Err(IoError::parse(1, None, "the pattern field requires the coordinate format"));
+    }
+
+    let (n, size) = match next_data_line(&mut lines)? {
+        Some(line) => line,
+        None => return Err(IoError::parse(1, None, "missing size line")),
+    };
+    let mut size_tokens = tokens(&size);
+    let rows: usize = parse_token(n, size_tokens.next(), "row count")?;
+    let cols: usize = parse_token(n, size_tokens.next(), "column count")?;
+    let too_large = || IoError::parse(n, None, format!("a {}x{} matrix is too large", rows, cols));
+    let size = rows.checked_mul(cols).ok_or_else(too_large)?;
+    let entries: usize = match format {
+        MatrixMarketFormat::Array if symmetry == Symmetry::Symmetric => {
+            rows.checked_add(1).and_then(|n| rows.checked_mul(n)).ok_or_else(too_large)? / 2
+        },
+        MatrixMarketFormat::Array => size,
+        MatrixMarketFormat::Coordinate => parse_token(n, size_tokens.next(), "entry count")?,
+    };
+    expect_end(n, size_tokens)?;
+
+    if symmetry == Symmetry::Symmetric && rows != cols {
+        return Err(IoError::parse(n, None, format!("a symmetric matrix can not be {}x{}", rows, cols)));
+    }
+
+    // the entries are read before the dense matrix is allocated, so a header can not claim
+    // more memory than the data behind it
+    let mut stored = Vec::new();
+    let size_line = n;
+    let mut last = n;
+
+    for k in 0..entries {
+        let (n, line) = match next_data_line(&mut lines)? {
+            Some(line) => line,
+            None => return Err(IoError::parse(last, None, format!("expected {} entries, found {}", entries, k))),
+        };
+        let mut values = tokens(&line);
+
+        let (i, j) = match format {
+            MatrixMarketFormat::Array if symmetry == Symmetry::Symmetric => {
+                // the lower triangle, column by column
+                let mut j = 0;
+                let mut k = k;
+                while k >= rows - j {
+                    k -= rows - j;
+                    j += 1;
+                }
+                (j + k, j)
+            },
+            MatrixMarketFormat::Array => (k % rows, k / rows),
+            MatrixMarketFormat::Coordinate => (
+                parse_index(n, values.next(), "row index", rows)?,
+                parse_index(n, values.next(), "column index", cols)?,
+            ),
+        };
+        let value = match field {
+            Field::Pattern => T::one(),
+            _ => parse_token(n, values.next(), "value")?,
+        };
+        expect_end(n, values)?;
+
+        if symmetry == Symmetry::Symmetric && i < j {
+            return Err(IoError::parse(n, None, "a symmetric matrix may only store its lower triangle"));
+        }
+        stored.push((i, j, value));
+        last = n;
+    }
+
+    if let Some((n, _)) = next_data_line(&mut lines)? {
+        return Err(IoError::parse(n, None, format!("unexpected data after {} entries", entries)));
+    }
+
+    // a few coordinate entries, or none for a matrix without columns, can still describe a
+    // dense matrix far larger than memory, so fail rather than abort if it can not be allocated
+    let mut matrix = match Matrix::try_from_fn(rows, cols, |_, _| T::zero()) {
+        Some(matrix) => matrix,
+        None => return Err(IoError::parse(size_line, None, format!("a {}x{} matrix does not fit in memory", rows, cols))),
+    };
+    for (i, j, value) in stored {
+        if symmetry == Symmetry::Symmetric {
+            matrix[j][i] = value.clone();
+        }
+        matrix[i][j] = value;
+    }
+    Ok(matrix)
+}
+
+/// writes `matrix` as a `general` Matrix Market file, `coordinate` output lists only the
+/// non-zero entries
+pub fn write_matrix_market<T, W>(mut writer: W, matrix: &Matrix<T>, format: MatrixMarketFormat) -> Result<(), IoError>
+    where T: MatrixMarketScalar + Zero + PartialEq,
+          W: Write,
+{
+    let rows = matrix.rows();
+    let cols = matrix.cols();
+    let zero = T::zero();
+
+    match format {
+        MatrixMarketFormat::Array => {
+            writeln!(writer, "%%MatrixMarket matrix array {} general", T::FIELD)?;
+            writeln!(writer, "{} {}", rows, cols)?;
+
+            for j in 0..cols {
+                for i in 0..rows {
+                    writeln!(writer, "{}", matrix[i][j])?;
+                }
+            }
+        },
+        MatrixMarketFormat::Coordinate => {
+            let entries = matrix.iter().filter(|x| **x != zero).count();
+
+            writeln!(writer, "%%MatrixMarket matrix coordinate {} general", T::FIELD)?;
+            writeln!(writer, "{} {} {}", rows, cols, entries)?;
+
+            for j in 0..cols {
+                for i in 0..rows {
+                    if matrix[i][j] != zero {
+                        writeln!(writer, "{} {} {}", i + 1, j + 1, matrix[i][j])?;
+                    }
+                }
+            }
+        },
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::string::ToString;
+
+    use super::*;
+
+
+    #[test]
+    fn test_read_coordinate_general() {
+        let file = "%%MatrixMarket matrix coordinate real general\n\
+                    % a comment\n\
+                    \n\
+                    3 2 3\n\
+                    1 1 1.5\n\
+                    3 2 -2\n\
+                    2 1 4e1\n";
+        let m: Matrix<f64> = read_matrix_market(file.as_bytes()).unwrap();
+        assert_eq!(m, Matrix::from([[1.5, 0.0], [40.0, 0.0], [0.0, -2.0]]));
+    }
+    #[test]
+    fn test_read_coordinate_symmetric_pattern() {
+        let file = "%%MatrixMarket matrix coordinate pattern symmetric\n3 3 3\n1 1\n3 1\n3 2\n";
+        let m: Matrix<i32> = read_matrix_market(file.as_bytes()).unwrap();
+        assert_eq!(m, Matrix::from([[1, 0, 1], [0, 0, 1], [1, 1, 0]]));
+    }
+    #[test]
+    fn test_read_array() {
+        let file = "%%MatrixMarket matrix array integer general\n2 3\n1\n4\n2\n5\n3\n6\n";
+        let m: Matrix<i64> = read_matrix_market(file.as_bytes()).unwrap();
+        assert_eq!(m, Matrix::from([[1, 2, 3], [4, 5, 6]]));
+
+        let file = "%%MatrixMarket matrix array integer symmetric\n3 3\n1\n2\n3\n4\n5\n6\n";
+        let m: Matrix<i64> = read_matrix_market(file.as_bytes()).unwrap();
+        assert_eq!(m, Matrix::from([[1, 2, 3], [2, 4, 5], [3, 5, 6]]));
+    }
+    #[test]
+    fn test_read_errors() {
+        let err = read_matrix_market::<f64, _>("%%MatrixMarket matrix coordinate complex general\n".as_bytes());
+        assert_eq!(err.unwrap_err().to_string(), "unsupported: field `complex`");
+
+        let err = read_matrix_market::<f64, _>("%%MatrixMarket matrix coordinate real general\n2 2 1\n1   3 1.0\n".as_bytes());
+        assert_eq!(err.unwrap_err().to_string(), "line 3, column 5: column index 3 out of range 1..=2");
+
+        let err = read_matrix_market::<i32, _>("%%MatrixMarket matrix array integer general\n1 2\n1\n2.5\n".as_bytes());
+        assert_eq!(err.unwrap_err().to_string(), "line 4, column 1: invalid value `2.5`");
+
+        let err = read_matrix_market::<i32, _>("%%MatrixMarket matrix array integer general\n2 1\n1\n".as_bytes());
+        assert_eq!(err.unwrap_err().to_string(), "line 3: expected 2 entries, found 1");
+
+        let err = read_matrix_market::<i32, _>("%%MatrixMarket matrix array integer general\n1 1\n1\n2\n".as_bytes());
+        assert_eq!(err.unwrap_err().to_string(), "line 4: unexpected data after 1 entries");
+    }
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_read_too_large() {
+        let err = read_matrix_market::<f64, _>("%%MatrixMarket matrix coordinate real general
+4294967296 4294967296 1
+1 1 1
+".as_bytes());
+        assert_eq!(err.unwrap_err().to_string(), "line 2: a 4294967296x4294967296 matrix is too large");
+
+        let err = read_matrix_market::<f64, _>("%%MatrixMarket matrix coordinate real general
+4000000000 4000000000 1
+1 1 1
+".as_bytes());
+        assert_eq!(err.unwrap_err().to_string(), "line 2: a 4000000000x4000000000 matrix does not fit in memory");
+
+        let err = read_matrix_market::<f64, _>("%%MatrixMarket matrix coordinate real general\n4611686018427387904 0 0\n".as_bytes());
+        assert_eq!(err.unwrap_err().to_string(), "line 2: a 4611686018427387904x0 matrix does not fit in memory");
+
+        // the header alone does not allocate the matrix it describes
+        let err = read_matrix_market::<f64, _>("%%MatrixMarket matrix array real general
+1000000 1000000
+1
+".as_bytes());
+        assert_eq!(err.unwrap_err().to_string(), "line 3: expected 1000000000000 entries, found 1");
+    }
+    #[test]
+    fn test_read_no_columns() {
+        let file = "%%MatrixMarket matrix coordinate real general\n3 0 0\n";
+        assert_eq!(read_matrix_market::<f64, _>(file.as_bytes()).unwrap(), Matrix::zeroed(3, 0));
+    }
+    #[test]
+    fn test_write_round_trip() {
+        let m: Matrix<f64> = Matrix::from([[1.0, 0.0, -2.5], [0.0, 0.0, 1e-7]]);
+
+        for &format in &[MatrixMarketFormat::Array, MatrixMarketFormat::Coordinate] {
+            let mut out = Vec::new();
+            write_matrix_market(&mut out, &m, format).unwrap();
+            assert_eq!(read_matrix_market::<f64, _>(&*out).unwrap(), m);
+        }
+
+        let mut out = Vec::new();
+        write_matrix_market(&mut out, &Matrix::<i32>::identity(2, 2), MatrixMarketFormat::Coordinate).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(),
+            "%%MatrixMarket matrix coordinate integer general\n2 2 2\n1 1 1\n2 2 1\n");
+    }
+}
@@ -0,0 +1,78 @@
+use core::fmt;
+
+use std::error;
+use std::io;
+use std::string::String;
+
+mod csv;
+mod matrix_market;
+mod npy;
+#[cfg(feature = "npz")]
+mod npz;
+
+
+pub use self::csv::CsvOptions;
+pub use self::matrix_market::{read_matrix_market, write_matrix_market, MatrixMarketFormat, MatrixMarketScalar};
+pub use self::npy::NpyElement;
+#[cfg(feature = "npz")]
+pub use self::npz::{NpzReader, NpzWriter};
+
+
+/// errors reading or writing matrix files
+#[derive(Debug)]
+pub enum IoError {
+    Io(io::Error),
+    /// malformed input, `line` and `column` are 1-based
+    Parse {
+        line: usize,
+        column: Option<usize>,
+        message: String,
+    },
+    /// malformed binary input
+    Format(String),
+    /// well-formed input using a feature the reader does not handle
+    Unsupported(String),
+}
+
+impl IoError {
+    #[inline]
+    pub(crate) fn parse<S: Into<String>>(line: usize, column: Option<usize>, message: S) -> Self {
+        IoError::Parse {
+            line: line,
+            column: column,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for IoError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IoError::Io(ref e) => fmt::Display::fmt(e, f),
+            IoError::Parse { line, column: Some(column), ref message } =>
+                write!(f, "line {}, column {}: {}", line, column, message),
+            IoError::Parse { line, column: None, ref message } =>
+                write!(f, "line {}: {}", line, message),
+            IoError::Format(ref message) => write!(f, "invalid format: {}", message),
+            IoError::Unsupported(ref message) => write!(f, "unsupported: {}", message),
+        }
+    }
+}
+
+impl error::Error for IoError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            IoError::Io(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for IoError {
+    #[inline(always)]
+    fn from(e: io::Error) -> Self {
+        IoError::Io(e)
+    }
+}
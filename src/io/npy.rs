@@ -0,0 +1,384 @@
+use core::convert::TryInto;
+use core::mem;
+use core::str;
+
+use std::format;
+use std::io::{Read, Write};
+use std::string::String;
+use std::vec::Vec;
+
+use super::super::matrix::Matrix;
+use super::super::vector::Vector;
+use super::IoError;
+
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+/// the total header length is padded to a multiple of this
+const ALIGN: usize = 64;
+
+
+/// element types that can be read from and written to NumPy `.npy` arrays
+pub trait NpyElement: Copy {
+    /// the dtype without its byte order, e.g. `f8`
+    const DTYPE: &'static str;
+
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+    fn from_be_bytes(bytes: &[u8]) -> Self;
+    fn write_le_bytes(&self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_npy_element {
+    ($T: ty, $dtype: expr) => (
+        impl NpyElement for $T {
+            const DTYPE: &'static str = $dtype;
+
+            #[inline(always)]
+            fn from_le_bytes(bytes: &[u8]) -> Self {
+                <$T>::from_le_bytes(bytes.try_into().unwrap())
+            }
+            #[inline(always)]
+            fn from_be_bytes(bytes: &[u8]) -> Self {
+                <$T>::from_be_bytes(bytes.try_into().unwrap())
+            }
+            #[inline(always)]
+            fn write_le_bytes(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    );
+}
+
+impl_npy_element!(f32, "f4");
+impl_npy_element!(f64, "f8");
+impl_npy_element!(i32, "i4");
+impl_npy_element!(i64, "i8");
+
+
+struct Header {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+/// parses the python dict literal of a `.npy` header
+struct HeaderParser<'a> {
+    text: &'a str,
+}
+
+impl<'a> HeaderParser<'a> {
+    #[inline]
+    fn error<T>(&self, expected: &str) -> Result<T, IoError> {
+        Err(IoError::Format(format!("expected {} in npy header, found `{}`", expected, self.text.trim_end())))
+    }
+    #[inline]
+    fn eat(&mut self, c: char) -> bool {
+        self.text = self.text.trim_start();
+
+        if self.text.starts_with(c) {
+            self.text = &self.text[c.len_utf8()..];
+            true
+        } else {
+            false
+        }
+    }
+    #[inline]
+    fn expect(&mut self, c: char) -> Result<(), IoError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            self.error(&format!("`{}`", c))
+        }
+    }
+    #[inline]
+    fn string(&mut self) -> Result<String, IoError> {
+        let quote = if self.eat('\'') { '\'' } else { self.expect('"')?; '"' };
+
+        match self.text.find(quote) {
+            Some(end) => {
+                let string = self.text[..end].into();
+                self.text = &self.text[end + 1..];
+                Ok(string)
+            },
+            None => self.error("a closing quote"),
+        }
+    }
+    #[inline]
+    fn boolean(&mut self) -> Result<bool, IoError> {
+        self.text = self.text.trim_start();
+
+        for &(word, value) in &[("True", true), ("False", false)] {
+            if self.text.starts_with(word) {
+                self.text = &self.text[word.len()..];
+                return Ok(value);
+            }
+        }
+        self.error("`True` or `False`")
+    }
+    #[inline]
+    fn shape(&mut self) -> Result<Vec<usize>, IoError> {
+        let mut shape = Vec::new();
+
+        self.expect('(')?;
+        while !self.eat(')') {
+            let end = self.text.find(|c: char| !c.is_ascii_digit()).unwrap_or(self.text.len());
+
+            match self.text[..end].parse() {
+                Ok(dim) => shape.push(dim),
+                Err(_) => return self.error("a dimension"),
+            }
+            self.text = &self.text[end..];
+
+            if !self.eat(',') {
+                self.expect(')')?;
+                break;
+            }
+        }
+        Ok(shape)
+    }
+
+    #[inline]
+    fn header(mut self) -> Result<Header, IoError> {
+        let mut descr = None;
+        let mut fortran_order = None;
+        let mut shape = None;
+
+        self.expect('{')?;
+        while !self.eat('}') {
+            let key = self.string()?;
+            self.expect(':')?;
+
+            match &*key {
+                "descr" => descr = Some(self.string()?),
+                "fortran_order" => fortran_order = Some(self.boolean()?),
+                "shape" => shape = Some(self.shape()?),
+                other => return Err(IoError::Format(format!("unknown npy header key `{}`", other))),
+            }
+
+            if !self.eat(',') {
+                self.expect('}')?;
+                break;
+            }
+        }
+        if !self.text.trim().is_empty() {
+            return self.error("the end of the header");
+        }
+
+        match (descr, fortran_order, shape) {
+            (Some(descr), Some(fortran_order), Some(shape)) => Ok(Header {
+                descr: descr,
+                fortran_order: fortran_order,
+                shape: shape,
+            }),
+            _ => Err(IoError::Format("npy header must have `descr`, `fortran_order` and `shape`".into())),
+        }
+    }
+}
+
+/// reads a `.npy` array, returning its shape, whether it is in Fortran order and its elements
+#[inline]
+fn read_array<T: NpyElement, R: Read>(mut reader: R) -> Result<(Vec<usize>, bool, Vec<T>), IoError> {
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+
+    if &magic[..6] != MAGIC {
+        return Err(IoError::Format("missing the NumPy magic string".into()));
+    }
+    let header_len = match magic[6] {
+        1 => {
+            let mut len = [0u8; 2];
+            reader.read_exact(&mut len)?;
+            u16::from_le_bytes(len) as usize
+        },
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            reader.read_exact(&mut len)?;
+            u32::from_le_bytes(len) as usize
+        },
+        major => return Err(IoError::Unsupported(format!("npy format version {}.{}", major, magic[7]))),
+    };
+
+    let mut header = Vec::new();
+    (&mut reader).take(header_len as u64).read_to_end(&mut header)?;
+    if header.len() != header_len {
+        return Err(IoError::Format("truncated npy header".into()));
+    }
+    let header = match str::from_utf8(&header) {
+        Ok(text) => HeaderParser { text: text }.header()?,
+        Err(_) => return Err(IoError::Format("npy header is not valid UTF-8".into())),
+    };
+
+    let little_endian = match header.descr.chars().next() {
+        Some('<') => true,
+        Some('>') => false,
+        Some('=') => cfg!(target_endian = "little"),
+        _ => return Err(IoError::Unsupported(format!("dtype `{}`", header.descr))),
+    };
+    if &header.descr[1..] != T::DTYPE {
+        return Err(IoError::Format(format!("expected dtype `{}`, found `{}`", T::DTYPE, header.descr)));
+    }
+
+    let size = mem::size_of::<T>();
+    let len = match header.shape.iter().try_fold(1usize, |len, &dim| len.checked_mul(dim)) {
+        Some(len) if len.checked_mul(size).is_some() => len,
+        _ => return Err(IoError::Format(format!("an array of shape {:?} is too large", header.shape))),
+    };
+
+    let mut bytes = Vec::new();
+    reader.take((len * size) as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != len * size {
+        return Err(IoError::Format(format!("expected {} bytes of data, found {}", len * size, bytes.len())));
+    }
+
+    let data = bytes.chunks(size)
+        .map(|bytes| if little_endian { T::from_le_bytes(bytes) } else { T::from_be_bytes(bytes) })
+        .collect();
+
+    Ok((header.shape, header.fortran_order, data))
+}
+
+/// writes a little-endian, C order, version 1.0 `.npy` array
+#[inline]
+fn write_array<'a, T, I, W>(mut writer: W, shape: &str, len: usize, data: I) -> Result<(), IoError>
+    where T: 'a + NpyElement,
+          I: Iterator<Item = &'a T>,
+          W: Write,
+{
+    let mut header = format!("{{'descr': '<{}', 'fortran_order': False, 'shape': {}, }}", T::DTYPE, shape);
+    let padding = ALIGN - (MAGIC.len() + 4 + header.len() + 1) % ALIGN;
+    header.extend((0..padding).map(|_| ' '));
+    header.push('\n');
+
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 4 + header.len() + len * mem::size_of::<T>());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    for x in data {
+        x.write_le_bytes(&mut bytes);
+    }
+
+    writer.write_all(&bytes)?;
+    writer.flush()?;
+    Ok(())
+}
+
+
+impl<T: NpyElement> Matrix<T> {
+    /// reads a 2-dimensional `.npy` array of either byte order in C or Fortran order
+    #[inline]
+    pub fn from_npy<R: Read>(reader: R) -> Result<Self, IoError> {
+        let (shape, fortran_order, data) = read_array::<T, R>(reader)?;
+
+        if shape.len() != 2 {
+            return Err(IoError::Format(format!("expected a 2-dimensional array, found shape {:?}", shape)));
+        }
+        let (rows, cols) = (shape[0], shape[1]);
+
+        // with no columns there is no data to bound the row count, and each row allocates
+        let matrix = if fortran_order {
+            Matrix::try_from_fn(rows, cols, |i, j| data[j * rows + i])
+        } else {
+            Matrix::try_from_fn(rows, cols, |i, j| data[i * cols + j])
+        };
+        matrix.ok_or_else(|| IoError::Format(format!("an array of shape {:?} does not fit in memory", shape)))
+    }
+    /// writes a little-endian, C order `.npy` array
+    #[inline]
+    pub fn to_npy<W: Write>(&self, writer: W) -> Result<(), IoError> {
+        let shape = format!("({}, {})", self.rows(), self.cols());
+        write_array(writer, &shape, self.rows() * self.cols(), self.iter())
+    }
+}
+
+impl<T: NpyElement> Vector<T> {
+    /// reads a 1-dimensional `.npy` array of either byte order
+    #[inline]
+    pub fn from_npy<R: Read>(reader: R) -> Result<Self, IoError> {
+        let (shape, _, data) = read_array::<T, R>(reader)?;
+
+        if shape.len() != 1 {
+            return Err(IoError::Format(format!("expected a 1-dimensional array, found shape {:?}", shape)));
+        }
+        Ok(data.into_iter().collect())
+    }
+    /// writes a little-endian `.npy` array
+    #[inline]
+    pub fn to_npy<W: Write>(&self, writer: W) -> Result<(), IoError> {
+        let shape = format!("({},)", self.len());
+        write_array(writer, &shape, self.len(), self.iter())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::string::ToString;
+
+    use super::*;
+
+
+    #[test]
+    fn test_read_npy_fixtures() {
+        let f: Matrix<f64> = Matrix::from([[1.5, -2.0, 3.0], [4.0, 5.25, -6.0]]);
+        let i: Matrix<i64> = Matrix::from([[1, -2, 3], [4, 5, -6]]);
+
+        assert_eq!(Matrix::<f64>::from_npy(&include_bytes!("fixtures/f64_le_c.npy")[..]).unwrap(), f);
+        assert_eq!(Matrix::<f32>::from_npy(&include_bytes!("fixtures/f32_be_fortran.npy")[..]).unwrap(),
+            f.map(|x| *x as f32));
+        assert_eq!(Matrix::<i32>::from_npy(&include_bytes!("fixtures/i32_be_c.npy")[..]).unwrap(),
+            i.map(|x| *x as i32));
+        assert_eq!(Matrix::<i64>::from_npy(&include_bytes!("fixtures/i64_le_fortran.npy")[..]).unwrap(), i);
+        assert_eq!(Vector::<f64>::from_npy(&include_bytes!("fixtures/vector_f64.npy")[..]).unwrap(),
+            Vector::from([0.5, 1.0, -1.5, 2.0]));
+    }
+    #[test]
+    fn test_write_npy_fixtures() {
+        let m = Matrix::<f64>::from_npy(&include_bytes!("fixtures/f64_le_c.npy")[..]).unwrap();
+        let mut out = Vec::new();
+        m.to_npy(&mut out).unwrap();
+        assert_eq!(&out[..], &include_bytes!("fixtures/f64_le_c.npy")[..]);
+
+        let v = Vector::<f64>::from_npy(&include_bytes!("fixtures/vector_f64.npy")[..]).unwrap();
+        let mut out = Vec::new();
+        v.to_npy(&mut out).unwrap();
+        assert_eq!(&out[..], &include_bytes!("fixtures/vector_f64.npy")[..]);
+    }
+    #[test]
+    fn test_npy_round_trip() {
+        let m: Matrix<i32> = Matrix::from_fn(3, 5, |i, j| (i * 5 + j) as i32 - 7);
+        let mut out = Vec::new();
+        m.to_npy(&mut out).unwrap();
+        assert_eq!(out.len() % ALIGN, (15 * 4) % ALIGN);
+        assert_eq!(Matrix::<i32>::from_npy(&*out).unwrap(), m);
+
+        let empty: Matrix<f32> = Matrix::zeroed(0, 3);
+        let mut out = Vec::new();
+        empty.to_npy(&mut out).unwrap();
+        assert_eq!(Matrix::<f32>::from_npy(&*out).unwrap(), empty);
+
+        let empty: Matrix<f32> = Matrix::zeroed(3, 0);
+        let mut out = Vec::new();
+        empty.to_npy(&mut out).unwrap();
+        assert_eq!(Matrix::<f32>::from_npy(&*out).unwrap(), empty);
+    }
+    #[test]
+    fn test_npy_errors() {
+        let fixture = &include_bytes!("fixtures/f64_le_c.npy")[..];
+
+        let err = Matrix::<f32>::from_npy(fixture).unwrap_err();
+        assert_eq!(err.to_string(), "invalid format: expected dtype `f4`, found `<f8`");
+
+        let err = Vector::<f64>::from_npy(fixture).unwrap_err();
+        assert_eq!(err.to_string(), "invalid format: expected a 1-dimensional array, found shape [2, 3]");
+
+        let err = Matrix::<f64>::from_npy(&fixture[..fixture.len() - 1]).unwrap_err();
+        assert_eq!(err.to_string(), "invalid format: expected 48 bytes of data, found 47");
+
+        assert!(Matrix::<f64>::from_npy(&b"NUMPY\x01\x00"[..]).is_err());
+
+        let mut out = Vec::new();
+        write_array::<f64, _, _>(&mut out, &format!("({}, 0)", usize::MAX / 4 + 1), 0, [].iter()).unwrap();
+        let err = Matrix::<f64>::from_npy(&*out).unwrap_err();
+        assert_eq!(err.to_string(), format!("invalid format: an array of shape [{}, 0] does not fit in memory", usize::MAX / 4 + 1));
+    }
+}
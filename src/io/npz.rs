@@ -0,0 +1,149 @@
+use std::format;
+use std::io::{Read, Seek, Write};
+use std::string::ToString;
+
+use zip::result::ZipError;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use super::super::matrix::Matrix;
+use super::super::vector::Vector;
+use super::npy::NpyElement;
+use super::IoError;
+
+
+impl From<ZipError> for IoError {
+    #[inline]
+    fn from(e: ZipError) -> Self {
+        match e {
+            ZipError::Io(e) => IoError::Io(e),
+            e => IoError::Format(e.to_string()),
+        }
+    }
+}
+
+
+/// reads named arrays from a NumPy `.npz` archive, stored or deflate compressed
+pub struct NpzReader<R> {
+    archive: ZipArchive<R>,
+}
+
+impl<R: Read + Seek> NpzReader<R> {
+    #[inline]
+    pub fn new(reader: R) -> Result<Self, IoError> {
+        Ok(NpzReader {
+            archive: ZipArchive::new(reader)?,
+        })
+    }
+
+    /// the names of the arrays in the archive, without the `.npy` extension, in no particular order
+    #[inline]
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.archive.file_names().map(|name| name.trim_end_matches(".npy"))
+    }
+
+    #[inline]
+    pub fn matrix<T: NpyElement>(&mut self, name: &str) -> Result<Matrix<T>, IoError> {
+        Matrix::from_npy(self.archive.by_name(&format!("{}.npy", name)).map_err(|e| missing(name, e))?)
+    }
+    #[inline]
+    pub fn vector<T: NpyElement>(&mut self, name: &str) -> Result<Vector<T>, IoError> {
+        Vector::from_npy(self.archive.by_name(&format!("{}.npy", name)).map_err(|e| missing(name, e))?)
+    }
+}
+
+#[inline]
+fn missing(name: &str, e: ZipError) -> IoError {
+    match e {
+        ZipError::FileNotFound => IoError::Format(format!("no array named `{}` in npz archive", name)),
+        e => e.into(),
+    }
+}
+
+
+/// writes named arrays to a NumPy `.npz` archive
+pub struct NpzWriter<W: Write + Seek> {
+    zip: ZipWriter<W>,
+    options: FileOptions,
+}
+
+impl<W: Write + Seek> NpzWriter<W> {
+    /// stores arrays uncompressed, like `numpy.savez`
+    #[inline]
+    pub fn new(writer: W) -> Self {
+        Self::with_compression(writer, CompressionMethod::Stored)
+    }
+    /// deflate compresses arrays, like `numpy.savez_compressed`
+    #[inline]
+    pub fn compressed(writer: W) -> Self {
+        Self::with_compression(writer, CompressionMethod::Deflated)
+    }
+    #[inline]
+    fn with_compression(writer: W, method: CompressionMethod) -> Self {
+        NpzWriter {
+            zip: ZipWriter::new(writer),
+            options: FileOptions::default().compression_method(method),
+        }
+    }
+
+    #[inline]
+    pub fn add_matrix<T: NpyElement>(&mut self, name: &str, matrix: &Matrix<T>) -> Result<(), IoError> {
+        self.zip.start_file(format!("{}.npy", name), self.options)?;
+        matrix.to_npy(&mut self.zip)
+    }
+    #[inline]
+    pub fn add_vector<T: NpyElement>(&mut self, name: &str, vector: &Vector<T>) -> Result<(), IoError> {
+        self.zip.start_file(format!("{}.npy", name), self.options)?;
+        vector.to_npy(&mut self.zip)
+    }
+
+    /// writes the archive's central directory, returning the underlying writer
+    #[inline]
+    pub fn finish(mut self) -> Result<W, IoError> {
+        Ok(self.zip.finish()?)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+    use std::vec::Vec;
+
+    use super::*;
+
+
+    #[test]
+    fn test_read_npz_fixtures() {
+        let a: Matrix<f64> = Matrix::from([[1.5, -2.0, 3.0], [4.0, 5.25, -6.0]]);
+        let b: Vector<f64> = Vector::from([0.5, 1.0, -1.5, 2.0]);
+
+        for &bytes in &[&include_bytes!("fixtures/stored.npz")[..], &include_bytes!("fixtures/deflate.npz")[..]] {
+            let mut npz = NpzReader::new(Cursor::new(bytes)).unwrap();
+            let mut names: Vec<&str> = npz.names().collect();
+            names.sort();
+            assert_eq!(names, ["a", "b"]);
+            assert_eq!(npz.matrix::<f64>("a").unwrap(), a);
+            assert_eq!(npz.vector::<f64>("b").unwrap(), b);
+            assert_eq!(npz.matrix::<f64>("c").unwrap_err().to_string(),
+                "invalid format: no array named `c` in npz archive");
+        }
+    }
+    #[test]
+    fn test_npz_round_trip() {
+        let a: Matrix<i64> = Matrix::from_fn(4, 3, |i, j| (i * j) as i64 - 2);
+        let b: Vector<f32> = Vector::from([1.0, 0.25]);
+
+        for &compressed in &[false, true] {
+            let cursor = Cursor::new(Vec::new());
+            let mut npz = if compressed { NpzWriter::compressed(cursor) } else { NpzWriter::new(cursor) };
+            npz.add_matrix("a", &a).unwrap();
+            npz.add_vector("b", &b).unwrap();
+            let bytes = npz.finish().unwrap().into_inner();
+
+            let mut npz = NpzReader::new(Cursor::new(bytes)).unwrap();
+            assert_eq!(npz.matrix::<i64>("a").unwrap(), a);
+            assert_eq!(npz.vector::<f32>("b").unwrap(), b);
+        }
+    }
+}
@@ -0,0 +1,455 @@
+use core::iter::Flatten;
+use core::ops::Index;
+use core::slice;
+
+use alloc::vec;
+
+use super::matrix::Matrix;
+use super::vector::Vector;
+
+
+impl<T> Matrix<T> {
+    #[inline(always)]
+    pub fn row_iter(&self) -> slice::Iter<'_, Vector<T>> {
+        (**self).iter()
+    }
+    /// iterates mutably over the rows as slices, which can not change length
+    #[inline(always)]
+    pub fn row_iter_mut(&mut self) -> RowIterMut<'_, T> {
+        RowIterMut {
+            rows: self.rows_mut().iter_mut(),
+        }
+    }
+
+    #[inline]
+    pub fn column(&self, j: usize) -> Column<'_, T> {
+        assert!(j < self.cols(), "column index {} out of range for a {}x{} matrix", j, self.rows(), self.cols());
+        Column {
+            matrix: self,
+            col: j,
+        }
+    }
+    #[inline(always)]
+    pub fn column_iter(&self) -> Columns<'_, T> {
+        Columns {
+            matrix: self,
+            front: 0,
+            back: self.cols(),
+        }
+    }
+
+    /// iterates over every element in row-major order
+    ///
+    /// this shadows the `iter` of the `[Vector<T>]` the matrix derefs to, which yielded rows,
+    /// use `row_iter` for those
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            rows: (**self).iter(),
+            row: [].iter(),
+        }
+    }
+    /// iterates mutably over every element in row-major order
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            rows: self.rows_mut().iter_mut(),
+            row: [].iter_mut(),
+        }
+    }
+    /// iterates over every element in row-major order along with its `(row, column)`
+    #[inline(always)]
+    pub fn indexed_iter(&self) -> IndexedIter<'_, T> {
+        IndexedIter {
+            matrix: self,
+            i: 0,
+            j: 0,
+        }
+    }
+}
+
+
+/// iterates mutably over the rows of a `Matrix` as slices
+pub struct RowIterMut<'a, T: 'a> {
+    rows: slice::IterMut<'a, Vector<T>>,
+}
+
+impl<'a, T: 'a> Iterator for RowIterMut<'a, T> {
+    type Item = &'a mut [T];
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows.next().map(|row| &mut **row)
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+impl<'a, T: 'a> DoubleEndedIterator for RowIterMut<'a, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.rows.next_back().map(|row| &mut **row)
+    }
+}
+impl<'a, T: 'a> ExactSizeIterator for RowIterMut<'a, T> {}
+
+
+/// a strided view of one column of a `Matrix`
+pub struct Column<'a, T: 'a> {
+    matrix: &'a Matrix<T>,
+    col: usize,
+}
+
+impl<'a, T: 'a> Clone for Column<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self { *self }
+}
+impl<'a, T: 'a> Copy for Column<'a, T> {}
+
+impl<'a, T: 'a> Column<'a, T> {
+    #[inline(always)]
+    pub fn index(&self) -> usize { self.col }
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.matrix.rows() }
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<&'a T> {
+        if i < self.len() {
+            Some(&self.matrix[i][self.col])
+        } else {
+            None
+        }
+    }
+    #[inline(always)]
+    pub fn iter(&self) -> ColumnIter<'a, T> {
+        ColumnIter {
+            rows: self.matrix.row_iter(),
+            col: self.col,
+        }
+    }
+}
+
+impl<'a, T: 'a> Index<usize> for Column<'a, T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.matrix[index][self.col]
+    }
+}
+
+impl<'a, T: 'a> IntoIterator for Column<'a, T> {
+    type Item = &'a T;
+    type IntoIter = ColumnIter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+
+/// iterates over the elements of a `Column`
+pub struct ColumnIter<'a, T: 'a> {
+    rows: slice::Iter<'a, Vector<T>>,
+    col: usize,
+}
+
+impl<'a, T: 'a> Iterator for ColumnIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let col = self.col;
+        self.rows.next().map(|row| &row[col])
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+impl<'a, T: 'a> DoubleEndedIterator for ColumnIter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let col = self.col;
+        self.rows.next_back().map(|row| &row[col])
+    }
+}
+impl<'a, T: 'a> ExactSizeIterator for ColumnIter<'a, T> {}
+
+
+/// iterates over the columns of a `Matrix`
+pub struct Columns<'a, T: 'a> {
+    matrix: &'a Matrix<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T: 'a> Iterator for Columns<'a, T> {
+    type Item = Column<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.front += 1;
+            Some(Column {
+                matrix: self.matrix,
+                col: self.front - 1,
+            })
+        } else {
+            None
+        }
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+impl<'a, T: 'a> DoubleEndedIterator for Columns<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(Column {
+                matrix: self.matrix,
+                col: self.back,
+            })
+        } else {
+            None
+        }
+    }
+}
+impl<'a, T: 'a> ExactSizeIterator for Columns<'a, T> {}
+
+
+/// iterates over the elements of a `Matrix` in row-major order
+pub struct Iter<'a, T: 'a> {
+    rows: slice::Iter<'a, Vector<T>>,
+    row: slice::Iter<'a, T>,
+}
+
+impl<'a, T: 'a> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(x) = self.row.next() {
+                return Some(x);
+            }
+            match self.rows.next() {
+                Some(row) => self.row = row.iter(),
+                None => return None,
+            }
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.row.len() + self.rows.as_slice().iter().map(|row| row.len()).sum::<usize>();
+        (len, Some(len))
+    }
+}
+impl<'a, T: 'a> ExactSizeIterator for Iter<'a, T> {}
+
+
+/// iterates mutably over the elements of a `Matrix` in row-major order
+pub struct IterMut<'a, T: 'a> {
+    rows: slice::IterMut<'a, Vector<T>>,
+    row: slice::IterMut<'a, T>,
+}
+
+impl<'a, T: 'a> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(x) = self.row.next() {
+                return Some(x);
+            }
+            match self.rows.next() {
+                Some(row) => self.row = row.iter_mut(),
+                None => return None,
+            }
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.row.len() + self.rows.as_slice().iter().map(|row| row.len()).sum::<usize>();
+        (len, Some(len))
+    }
+}
+impl<'a, T: 'a> ExactSizeIterator for IterMut<'a, T> {}
+
+
+/// iterates over the elements of a `Matrix` in row-major order, yielding `(row, column, &element)`
+pub struct IndexedIter<'a, T: 'a> {
+    matrix: &'a Matrix<T>,
+    i: usize,
+    j: usize,
+}
+
+impl<'a, T: 'a> Iterator for IndexedIter<'a, T> {
+    type Item = (usize, usize, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i < self.matrix.rows() && self.j < self.matrix.cols() {
+            let (i, j) = (self.i, self.j);
+
+            self.j += 1;
+            if self.j == self.matrix.cols() {
+                self.j = 0;
+                self.i += 1;
+            }
+
+            Some((i, j, &self.matrix[i][j]))
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.j < self.matrix.cols() {
+            (self.matrix.rows() - self.i) * self.matrix.cols() - self.j
+        } else {
+            0
+        };
+        (len, Some(len))
+    }
+}
+impl<'a, T: 'a> ExactSizeIterator for IndexedIter<'a, T> {}
+
+
+/// iterates by value over the elements of a `Matrix` in row-major order
+pub struct IntoIter<T> {
+    iter: Flatten<vec::IntoIter<Vector<T>>>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<T> IntoIterator for Matrix<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.into_rows().into_iter().flatten(),
+        }
+    }
+}
+impl<'a, T: 'a> IntoIterator for &'a Matrix<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl<'a, T: 'a> IntoIterator for &'a mut Matrix<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+
+    #[test]
+    fn test_row_iter() {
+        let a: Matrix<usize> = Matrix::identity(2, 3);
+        let rows: Vec<usize> = a.row_iter().map(|row| row.sum()).collect();
+        assert_eq!(rows, [1, 1]);
+    }
+    #[test]
+    fn test_row_iter_mut() {
+        let mut a: Matrix<usize> = Matrix::identity(2, 3);
+        for (i, row) in a.row_iter_mut().enumerate() {
+            row[2] = i + 4;
+        }
+        a.row_iter_mut().next_back().unwrap().swap(0, 1);
+        assert_eq!(a, Matrix::from_row_slice(2, 3, &[1, 0, 4, 1, 0, 5]));
+        assert_eq!(a.row_iter_mut().len(), 2);
+    }
+    #[test]
+    fn test_column_iter() {
+        let mut a: Matrix<usize> = Matrix::identity(2, 3);
+        a[1][2] = 5;
+        let cols: Vec<usize> = a.column_iter().flat_map(|col| col.iter().cloned()).collect();
+        assert_eq!(cols, [1, 0, 0, 1, 0, 5]);
+
+        let last = a.column_iter().next_back().unwrap();
+        assert_eq!(last.index(), 2);
+        assert_eq!(last[1], 5);
+        assert_eq!(last.get(2), None);
+        assert_eq!(a.column(1).iter().rev().collect::<Vec<_>>(), [&1, &0]);
+    }
+    #[test]
+    fn test_element_iter() {
+        let mut a: Matrix<usize> = Matrix::identity(2, 2);
+        assert_eq!(a.iter().len(), 4);
+        assert_eq!(a.iter().cloned().collect::<Vec<_>>(), [1, 0, 0, 1]);
+
+        let mut iter = a.iter_mut();
+        assert_eq!(iter.len(), 4);
+        iter.next();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+
+        for (k, x) in a.iter_mut().enumerate() {
+            *x += k;
+        }
+        assert_eq!(a.iter().cloned().collect::<Vec<_>>(), [1, 1, 2, 4]);
+    }
+    #[test]
+    fn test_into_iter() {
+        let mut a: Matrix<usize> = Matrix::identity(2, 2);
+        for x in &mut a {
+            *x += 1;
+        }
+        assert_eq!((&a).into_iter().sum::<usize>(), 6);
+        assert_eq!(a.into_iter().collect::<Vec<_>>(), [2, 1, 1, 2]);
+    }
+    #[test]
+    fn test_indexed_iter() {
+        let a: Matrix<usize> = Matrix::identity(2, 2);
+        let items: Vec<(usize, usize, usize)> = a.indexed_iter().map(|(i, j, x)| (i, j, *x)).collect();
+        assert_eq!(items, [(0, 0, 1), (0, 1, 0), (1, 0, 0), (1, 1, 1)]);
+    }
+    #[test]
+    fn test_from_iter() {
+        let a: Matrix<usize> = Matrix::identity(3, 3);
+        let b: Matrix<usize> = a.row_iter().map(|row| row.map(|x| x * 2)).collect();
+        assert_eq!(b, &a * &2);
+
+        let v: Vector<usize> = (1..4).collect();
+        assert_eq!(&*v, &[1, 2, 3]);
+    }
+    #[test]
+    #[should_panic]
+    fn test_from_iter_ragged() {
+        let rows = [(0..2).collect::<Vector<usize>>(), (0..3).collect()];
+        let _: Matrix<usize> = rows.iter().cloned().collect();
+    }
+}
@@ -1,14 +1,69 @@
+//! dense linear algebra over `Vector<T>` and `Matrix<T>`
+//!
+//! the crate is `no_std` but requires the `alloc` crate, vectors and matrices are backed by
+//! heap allocations
 #![no_std]
 
 
-extern crate array;
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+extern crate libm;
 extern crate one;
 extern crate zero;
 
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "npz")]
+extern crate zip;
 
+#[cfg(all(test, feature = "serde"))]
+extern crate bincode;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+
+
+#[macro_use]
+mod macros;
+
+mod block;
+#[cfg(feature = "bytemuck")]
+mod bytes;
+mod convert;
+mod display;
+mod float;
+mod geometry;
+#[cfg(feature = "std")]
+mod io;
+mod iter;
 mod matrix;
+mod matrix_fn;
+mod norm;
+mod permutation;
+mod product;
+mod quaternion;
+#[cfg(feature = "serde")]
+mod serialize;
+mod strassen;
+mod structure;
+mod transform;
 mod vector;
+mod view;
 
 
+#[cfg(feature = "bytemuck")]
+pub use bytes::{BytesElement, BytesError};
+pub use float::Float;
+#[cfg(feature = "std")]
+pub use io::{read_matrix_market, write_matrix_market, CsvOptions, IoError, MatrixMarketFormat, MatrixMarketScalar, NpyElement};
+#[cfg(feature = "npz")]
+pub use io::{NpzReader, NpzWriter};
+pub use iter::{Column, ColumnIter, Columns, IndexedIter, IntoIter, Iter, IterMut, RowIterMut};
 pub use matrix::Matrix;
+pub use permutation::Permutation;
+pub use quaternion::Quaternion;
+pub use strassen::STRASSEN_CUTOFF;
 pub use vector::Vector;
+pub use view::{MatrixView, RowIter};
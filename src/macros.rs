@@ -0,0 +1,70 @@
+/// builds a `Vector` from a list of elements
+///
+/// ```
+/// #[macro_use] extern crate linalg;
+///
+/// # fn main() {
+/// let v: linalg::Vector<i32> = vector![1, 2, 3];
+/// assert_eq!(v[2], 3);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! vector {
+    ($($x: expr),* $(,)*) => (
+        $crate::Vector::from([$($x),*])
+    );
+}
+
+/// builds a `Matrix` from rows separated by `;`, rows of different lengths fail to compile
+///
+/// ```
+/// #[macro_use] extern crate linalg;
+///
+/// # fn main() {
+/// let m: linalg::Matrix<i32> = matrix![
+///     1, 2;
+///     3, 4
+/// ];
+/// assert_eq!(m[1][0], 3);
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// #[macro_use] extern crate linalg;
+///
+/// # fn main() {
+/// let m: linalg::Matrix<i32> = matrix![1, 2; 3];
+/// # }
+/// ```
+#[macro_export]
+macro_rules! matrix {
+    ($($($x: expr),+);+ $(;)*) => (
+        $crate::Matrix::from([$([$($x),+]),+])
+    );
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::super::{Matrix, Vector};
+
+
+    #[test]
+    fn test_vector_macro() {
+        let v: Vector<usize> = vector![1, 2, 3,];
+        assert_eq!(v, Vector::from_slice(&[1, 2, 3]));
+    }
+    #[test]
+    fn test_matrix_macro() {
+        let m: Matrix<usize> = matrix![
+            1, 0, 0;
+            0, 1, 0;
+            0, 0, 1
+        ];
+        assert_eq!(m, Matrix::identity(3, 3));
+
+        let row: Matrix<usize> = matrix![1, 2, 3];
+        assert_eq!(row.rows(), 1);
+        assert_eq!(row.cols(), 3);
+    }
+}
@@ -1,6 +1,9 @@
 use core::fmt;
+use core::iter::FromIterator;
 use core::ops::*;
 
+use alloc::vec::Vec;
+
 use one::One;
 use zero::Zero;
 
@@ -14,76 +17,154 @@ pub struct Matrix<T> {
     data: Vector<Vector<T>>,
 }
 
-unsafe impl<T: Send> Send for Matrix<T> {}
-unsafe impl<T: Sync> Sync for Matrix<T> {}
-
 impl<T: Default> Matrix<T> {
     #[inline]
     pub fn new(rows: usize, cols: usize) -> Self {
-        let mut data = Vector::zeroed(rows);
+        Matrix::from_fn(rows, cols, |_, _| T::default())
+    }
+}
 
-        for row in data.iter_mut() {
-            *row = Vector::new(cols);
-        }
+impl<T: Zero + One> Matrix<T> {
+    #[inline]
+    pub fn identity(rows: usize, cols: usize) -> Self {
+        Matrix::from_fn(rows, cols, |i, j| if i == j { T::one() } else { T::zero() })
+    }
+}
 
+impl<T> Matrix<T> {
+    /// builds a `rows` x `cols` matrix whose element at `(i, j)` is `f(i, j)`, calling `f`
+    /// in row-major order
+    #[inline]
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Self
+        where F: FnMut(usize, usize) -> T,
+    {
         Matrix {
             rows: rows,
             cols: cols,
-            data: data,
+            data: Vector::from_fn(rows, |i| Vector::from_fn(cols, |j| f(i, j))),
         }
     }
-}
-
-impl<T: One> Matrix<T> {
-    #[inline]
-    pub fn identity(rows: usize, cols: usize) -> Self {
-        let mut data = Vector::zeroed(rows);
-
-        let mut i = 0;
-        for row in data.iter_mut() {
-            let mut new_row = Vector::zeroed(cols);
-            new_row[i] = T::one();
-            *row = new_row;
-            i += 1;
+    /// `from_fn` for shapes read from untrusted input, `None` rather than aborting if the
+    /// elements or the rows, which take space even with no columns, can not be allocated
+    pub(crate) fn try_from_fn<F>(rows: usize, cols: usize, mut f: F) -> Option<Self>
+        where F: FnMut(usize, usize) -> T,
+    {
+        Vec::<T>::new().try_reserve_exact(rows.checked_mul(cols)?).ok()?;
+
+        let mut data = Vec::new();
+        data.try_reserve_exact(rows).ok()?;
+        for i in 0..rows {
+            data.push(Vector::from_fn(cols, |j| f(i, j)));
         }
 
-        Matrix {
+        Some(Matrix {
             rows: rows,
             cols: cols,
-            data: data,
+            data: Vector::from(data),
+        })
+    }
+}
+
+impl<T> Matrix<T>
+    where T: Clone,
+{
+    #[inline]
+    pub fn from_rows(rows: &[Vector<T>]) -> Self {
+        let cols = rows.first().map_or(0, |row| row.len());
+        for (i, row) in rows.iter().enumerate() {
+            assert!(row.len() == cols, "row {} has length {}, expected {}", i, row.len(), cols);
         }
+        Matrix::from_fn(rows.len(), cols, |i, j| rows[i][j].clone())
+    }
+    #[inline]
+    pub fn from_columns(cols: &[Vector<T>]) -> Self {
+        let rows = cols.first().map_or(0, |col| col.len());
+        for (j, col) in cols.iter().enumerate() {
+            assert!(col.len() == rows, "column {} has length {}, expected {}", j, col.len(), rows);
+        }
+        Matrix::from_fn(rows, cols.len(), |i, j| cols[j][i].clone())
+    }
+    /// builds a `rows` x `cols` matrix from `data` laid out in row-major order
+    #[inline]
+    pub fn from_row_slice(rows: usize, cols: usize, data: &[T]) -> Self {
+        assert!(data.len() == rows * cols,
+            "a {}x{} matrix needs {} elements, got {}", rows, cols, rows * cols, data.len());
+        Matrix::from_fn(rows, cols, |i, j| data[i * cols + j].clone())
     }
 }
 
-impl<T> Matrix<T> {
+impl<T> Matrix<T>
+    where T: Zero + Clone,
+{
+    /// builds a square matrix with `diagonal` on its main diagonal and zeros elsewhere
+    #[inline]
+    pub fn from_diagonal(diagonal: &Vector<T>) -> Self {
+        let size = diagonal.len();
+        Matrix::from_fn(size, size, |i, j| if i == j { diagonal[i].clone() } else { T::zero() })
+    }
+}
 
+impl<T: Zero> Matrix<T> {
+    /// builds a `rows` x `cols` matrix filled with `T::zero()`
     #[inline]
     pub fn zeroed(rows: usize, cols: usize) -> Self {
-        let mut data = Vector::zeroed(rows);
-
-        for row in data.iter_mut() {
-            *row = Vector::zeroed(cols);
-        }
-
         Matrix {
             rows: rows,
             cols: cols,
-            data: data,
+            data: Vector::of_vectors(rows, cols),
         }
     }
+}
 
+impl<T> Matrix<T> {
     #[inline(always)]
     pub fn rows(&self) -> usize { self.rows }
     #[inline(always)]
     pub fn cols(&self) -> usize { self.cols }
+    /// returns true if the matrix has no elements, i.e. either dimension is zero
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.rows == 0 || self.cols == 0 }
 
+    /// consumes the matrix, returning its rows
     #[inline(always)]
-    pub fn get(&self, i: usize, j: usize) -> &T {
-        self.data.get(i).get(j)
+    pub fn into_rows(self) -> Vector<Vector<T>> {
+        self.data
     }
+
+    /// the rows, mutably, for use within the crate by code that keeps them all `cols` long
     #[inline(always)]
-    pub fn get_mut(&mut self, i: usize, j: usize) -> &mut T {
-        self.data.get_mut(i).get_mut(j)
+    pub(crate) fn rows_mut(&mut self) -> &mut [Vector<T>] {
+        &mut self.data
+    }
+
+    /// returns the element at `(i, j)`, or `None` if either index is out of range
+    #[inline(always)]
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.data.get(i).and_then(|row| row.get(j))
+    }
+    /// returns the element at `(i, j)` mutably, or `None` if either index is out of range
+    #[inline(always)]
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        self.data.get_mut(i).and_then(|row| row.get_mut(j))
+    }
+
+    /// returns the element at `(i, j)` without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// `i` must be less than `self.rows()` and `j` less than `self.cols()`
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, i: usize, j: usize) -> &T {
+        self.data.get_unchecked(i).get_unchecked(j)
+    }
+    /// returns the element at `(i, j)` mutably without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// `i` must be less than `self.rows()` and `j` less than `self.cols()`
+    #[inline(always)]
+    pub unsafe fn get_unchecked_mut(&mut self, i: usize, j: usize) -> &mut T {
+        self.data.get_unchecked_mut(i).get_unchecked_mut(j)
     }
 }
 
@@ -92,25 +173,219 @@ impl<T> Matrix<T>
 {
     #[inline]
     pub fn transpose(&self) -> Self {
-        let mut matrix = Matrix::<T>::zeroed(self.cols, self.rows);
+        Matrix::from_fn(self.cols, self.rows, |i, j| self[j][i].clone())
+    }
+    /// copies the elements into a vector in row-major order
+    #[inline]
+    pub fn flatten(&self) -> Vector<T> {
+        self.iter().cloned().collect()
+    }
+}
 
-        for i in 0..self.rows {
-            for j in 0..self.cols {
-                matrix[j][i] = self[i][j].clone();
+impl<T> Matrix<T> {
+    /// reinterprets the elements in row-major order as a `rows` x `cols` matrix, panics
+    /// unless the element count is unchanged
+    #[inline]
+    pub fn reshape(self, rows: usize, cols: usize) -> Self {
+        assert!(rows.checked_mul(cols) == Some(self.rows * self.cols),
+            "can not reshape a {}x{} matrix into {}x{}", self.rows, self.cols, rows, cols);
+        self.into_iter().collect::<Vector<T>>().into_matrix(rows, cols)
+    }
+
+    /// swaps rows `i` and `j`
+    #[inline]
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        assert!(i < self.rows && j < self.rows,
+            "row indices ({}, {}) out of range for a {}x{} matrix", i, j, self.rows, self.cols);
+        self.data.swap(i, j);
+    }
+    /// swaps columns `i` and `j`
+    #[inline]
+    pub fn swap_columns(&mut self, i: usize, j: usize) {
+        assert!(i < self.cols && j < self.cols,
+            "column indices ({}, {}) out of range for a {}x{} matrix", i, j, self.rows, self.cols);
+        for row in self.data.iter_mut() {
+            row.swap(i, j);
+        }
+    }
+}
+
+impl<T> Matrix<T> {
+    /// appends `row` to the bottom of the matrix
+    #[inline(always)]
+    pub fn push_row(&mut self, row: Vector<T>) {
+        let rows = self.rows;
+        self.insert_row(rows, row);
+    }
+    /// inserts `row` before row `i`, a 0x0 matrix takes its column count from `row`
+    #[inline]
+    pub fn insert_row(&mut self, i: usize, row: Vector<T>) {
+        assert!(i <= self.rows, "row index {} out of range for a {}x{} matrix", i, self.rows, self.cols);
+        if self.rows == 0 && self.cols == 0 {
+            self.cols = row.len();
+        }
+        assert!(row.len() == self.cols,
+            "row has length {}, expected {} for a {}x{} matrix", row.len(), self.cols, self.rows, self.cols);
+        self.data.insert(i, row);
+        self.rows += 1;
+    }
+    /// removes and returns row `i`
+    #[inline]
+    pub fn remove_row(&mut self, i: usize) -> Vector<T> {
+        assert!(i < self.rows, "row index {} out of range for a {}x{} matrix", i, self.rows, self.cols);
+        self.rows -= 1;
+        self.data.remove(i)
+    }
+
+    /// appends `column` to the right of the matrix
+    #[inline(always)]
+    pub fn push_column(&mut self, column: Vector<T>) {
+        let cols = self.cols;
+        self.insert_column(cols, column);
+    }
+    /// inserts `column` before column `j`, a 0x0 matrix takes its row count from `column`
+    #[inline]
+    pub fn insert_column(&mut self, j: usize, mut column: Vector<T>) {
+        assert!(j <= self.cols, "column index {} out of range for a {}x{} matrix", j, self.rows, self.cols);
+        if self.rows == 0 && self.cols == 0 {
+            self.rows = column.len();
+            self.data = Vector::from_fn(column.len(), |_| Vector::with_capacity(1));
+        }
+        assert!(column.len() == self.rows,
+            "column has length {}, expected {} for a {}x{} matrix", column.len(), self.rows, self.rows, self.cols);
+
+        for row in self.data.iter_mut().rev() {
+            if let Some(x) = column.pop() {
+                row.insert(j, x);
             }
         }
+        self.cols += 1;
+    }
+    /// removes and returns column `j`
+    #[inline]
+    pub fn remove_column(&mut self, j: usize) -> Vector<T> {
+        assert!(j < self.cols, "column index {} out of range for a {}x{} matrix", j, self.rows, self.cols);
+        self.cols -= 1;
+        let data = &mut self.data;
+        Vector::from_fn(self.rows, |i| data[i].remove(j))
+    }
+}
 
-        matrix
+impl<T> Matrix<T> {
+    #[inline]
+    pub fn map<U, F>(&self, mut f: F) -> Matrix<U>
+        where F: FnMut(&T) -> U,
+    {
+        Matrix::from_fn(self.rows, self.cols, |i, j| f(&self[i][j]))
+    }
+    #[inline]
+    pub fn map_inplace<F>(&mut self, mut f: F)
+        where F: FnMut(&mut T),
+    {
+        for row in self.data.iter_mut() {
+            row.map_inplace(&mut f);
+        }
+    }
+    #[inline]
+    pub fn zip_map<U, V, F>(&self, other: &Matrix<U>, mut f: F) -> Matrix<V>
+        where F: FnMut(&T, &U) -> V,
+    {
+        assert!(self.rows == other.rows() && self.cols == other.cols(),
+            "A, B zip, A's shape {}x{} does not match B's shape {}x{}",
+            self.rows, self.cols, other.rows(), other.cols());
+        Matrix::from_fn(self.rows, self.cols, |i, j| f(&self[i][j], &other[i][j]))
+    }
+    #[inline]
+    pub fn zip_apply<U, F>(&mut self, other: &Matrix<U>, mut f: F)
+        where F: FnMut(&mut T, &U),
+    {
+        assert!(self.rows == other.rows() && self.cols == other.cols(),
+            "A, B zip, A's shape {}x{} does not match B's shape {}x{}",
+            self.rows, self.cols, other.rows(), other.cols());
+        for i in 0..self.rows {
+            self.data[i].zip_apply(&other.data[i], &mut f);
+        }
+    }
+    /// folds every element in row-major order
+    #[inline]
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+        where F: FnMut(B, &T) -> B,
+    {
+        self.iter().fold(init, f)
+    }
+}
+
+impl<T> Matrix<T>
+    where T: PartialOrd,
+{
+    /// returns the smallest element, or `None` if the matrix is empty
+    #[inline]
+    pub fn min(&self) -> Option<&T> {
+        let mut out: Option<&T> = None;
+        for row in self.row_iter() {
+            match (out, row.min()) {
+                (Some(m), Some(x)) if x < m => out = Some(x),
+                (None, x) => out = x,
+                _ => (),
+            }
+        }
+        out
+    }
+    /// returns the largest element, or `None` if the matrix is empty
+    #[inline]
+    pub fn max(&self) -> Option<&T> {
+        self.argmax().map(|(i, j)| &self[i][j])
+    }
+    /// returns the `(row, column)` of the first largest element in row-major order,
+    /// or `None` if the matrix is empty
+    #[inline]
+    pub fn argmax(&self) -> Option<(usize, usize)> {
+        let mut out: Option<(usize, usize)> = None;
+        for i in 0..self.rows {
+            match (out, self.data[i].argmax()) {
+                (Some((k, l)), Some(j)) if self[i][j] > self[k][l] => out = Some((i, j)),
+                (None, Some(j)) => out = Some((i, j)),
+                _ => (),
+            }
+        }
+        out
     }
 }
 
 impl<T> Matrix<T>
     where T: Zero + Clone + AddAssign<T>,
+{
+    #[inline]
+    pub fn sum(&self) -> T {
+        let mut out = T::zero();
+        for row in self.row_iter() {
+            out += row.sum();
+        }
+        out
+    }
+}
+
+impl<T> Matrix<T>
+    where T: One + Clone + MulAssign<T>,
+{
+    #[inline]
+    pub fn product(&self) -> T {
+        let mut out = T::one();
+        for row in self.row_iter() {
+            out *= row.product();
+        }
+        out
+    }
+}
+
+impl<T> Matrix<T>
+    where T: One + Zero + Clone + AddAssign<T>,
           for<'a> T: Mul<&'a T, Output = T>,
           for<'a> &'a T: Neg<Output = T>,
           for<'a, 'b> &'a T: Mul<&'b T, Output = T> +
                              Sub<&'b T, Output = T>,
 {
+    /// returns the determinant, which is one for a 0x0 matrix
     #[inline(always)]
     pub fn determinant(&self) -> T {
         if self.rows == self.cols {
@@ -165,13 +440,15 @@ fn get_cofactor<T>(out: &mut Vector<Vector<T>>, m: &Vector<Vector<T>>, p: usize,
 
 #[inline]
 fn determinant<T>(m: &Vector<Vector<T>>, size: usize) -> T
-    where T: Zero + Clone + AddAssign<T>,
+    where T: One + Zero + Clone + AddAssign<T>,
           for<'a> T: Mul<&'a T, Output = T>,
           for<'a> &'a T: Neg<Output = T>,
           for<'a, 'b> &'a T: Mul<&'b T, Output = T> +
                              Sub<&'b T, Output = T>,
 {
-    if size == 1 {
+    if size == 0 {
+        T::one()
+    } else if size == 1 {
         m[0][0].clone()
     } else {
         let mut tmp = Vector::of_vectors(size, size);
@@ -248,6 +525,25 @@ fn inverse<T>(out: &mut Vector<Vector<T>>, m: &Vector<Vector<T>>, size: usize)
     }
 }
 
+/// builds a `Matrix` from an iterator of rows, panicking if the rows differ in length
+impl<T> FromIterator<Vector<T>> for Matrix<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Vector<T>>>(iter: I) -> Self {
+        let data: Vector<Vector<T>> = iter.into_iter().collect();
+        let cols = data.first().map_or(0, |row| row.len());
+
+        for (i, row) in data.iter().enumerate() {
+            assert!(row.len() == cols, "row {} has length {}, expected {}", i, row.len(), cols);
+        }
+
+        Matrix {
+            rows: data.len(),
+            cols: cols,
+            data: data,
+        }
+    }
+}
+
 impl<T> Deref for Matrix<T> {
     type Target = [Vector<T>];
 
@@ -256,15 +552,10 @@ impl<T> Deref for Matrix<T> {
         &*self.data
     }
 }
-impl<T> DerefMut for Matrix<T> {
-    #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut *self.data
-    }
-}
 
+/// rows are handed out as slices, so they can not change length behind the matrix's back
 impl<T> Index<usize> for Matrix<T> {
-    type Output = Vector<T>;
+    type Output = [T];
 
     #[inline(always)]
     fn index(&self, index: usize) -> &Self::Output {
@@ -278,6 +569,29 @@ impl<T> IndexMut<usize> for Matrix<T> {
     }
 }
 
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        let (rows, cols) = (self.rows, self.cols);
+        match self.get(i, j) {
+            Some(x) => x,
+            None => panic!("index ({}, {}) out of range for a {}x{} matrix", i, j, rows, cols),
+        }
+    }
+}
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    #[inline]
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        let (rows, cols) = (self.rows, self.cols);
+        match self.get_mut(i, j) {
+            Some(x) => x,
+            None => panic!("index ({}, {}) out of range for a {}x{} matrix", i, j, rows, cols),
+        }
+    }
+}
+
 impl<T: fmt::Debug> fmt::Debug for Matrix<T> {
     #[inline(always)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -301,12 +615,13 @@ impl<'out, T> Neg for &'out Matrix<T>
     }
 }
 
-impl<'out, 'a, 'b, T> Matrix<T>
-    where T: 'a + 'b + AddAssign<T>,
-          &'a T: Mul<&'b T, Output = T>,
-{
+impl<T: AddAssign<T>> Matrix<T> {
+    /// the kernel of `mul` with the element product given by `product`, for element types
+    /// such as a generic `Float` whose references are not known to multiply
     #[inline]
-    pub fn mul(out: &'out mut Matrix<T>, a: &'a Matrix<T>, b: &'b Matrix<T>) -> &'out mut Matrix<T> {
+    pub(crate) fn mul_with<'a, 'b, F>(out: &mut Matrix<T>, a: &'a Matrix<T>, b: &'b Matrix<T>, mut product: F)
+        where F: FnMut(&'a T, &'b T) -> T,
+    {
         let a_cols = a.cols();
         let a_rows = a.rows();
         let b_cols = b.cols();
@@ -315,11 +630,21 @@ impl<'out, 'a, 'b, T> Matrix<T>
 
         for i in 0..a_rows {
     		for j in 0..b_cols {
-    			for k in 0..b_cols {
-    				out[i][j] += &a[i][k] * &b[k][j];
+    			for k in 0..a_cols {
+    				out[i][j] += product(&a[i][k], &b[k][j]);
     			}
     		}
     	}
+    }
+}
+
+impl<'out, 'a, 'b, T> Matrix<T>
+    where T: 'a + 'b + AddAssign<T>,
+          &'a T: Mul<&'b T, Output = T>,
+{
+    #[inline]
+    pub fn mul(out: &'out mut Matrix<T>, a: &'a Matrix<T>, b: &'b Matrix<T>) -> &'out mut Matrix<T> {
+        Matrix::mul_with(out, a, b, |x, y| x * y);
         out
     }
     #[inline]
@@ -337,7 +662,7 @@ impl<'out, 'a, 'b, T> Matrix<T>
 }
 
 impl<'a, 'b, T> Mul<&'b Matrix<T>> for  &'a Matrix<T>
-    where T: 'a + 'b + AddAssign<T>,
+    where T: 'a + 'b + Zero + AddAssign<T>,
           &'a T: Mul<&'b T, Output = T>,
 {
     type Output = Matrix<T>;
@@ -351,7 +676,7 @@ impl<'a, 'b, T> Mul<&'b Matrix<T>> for  &'a Matrix<T>
 }
 
 impl<'a, 'b, T> Mul<&'b T> for  &'a Matrix<T>
-    where T: AddAssign<T>,
+    where T: Zero + AddAssign<T>,
           &'a T: Mul<&'b T, Output = T>,
 {
     type Output = Matrix<T>;
@@ -364,6 +689,37 @@ impl<'a, 'b, T> Mul<&'b T> for  &'a Matrix<T>
     }
 }
 
+impl<T> Matrix<T>
+    where T: Zero + One + Clone + AddAssign<T>,
+          for<'a, 'b> &'a T: Mul<&'b T, Output = T>,
+{
+    /// raises a square matrix to the power `exp` by repeated squaring, the identity for
+    /// `exp == 0`
+    #[inline]
+    pub fn pow(&self, mut exp: u32) -> Matrix<T> {
+        assert!(self.rows == self.cols, "can not raise a {}x{} matrix to a power", self.rows, self.cols);
+
+        let mut base = self.clone();
+        let mut out: Option<Matrix<T>> = None;
+
+        loop {
+            if exp & 1 == 1 {
+                out = Some(match out {
+                    Some(out) => &out * &base,
+                    None => base.clone(),
+                });
+            }
+            exp >>= 1;
+            if exp == 0 {
+                break;
+            }
+            base = &base * &base;
+        }
+
+        out.unwrap_or_else(|| Matrix::identity(self.rows, self.cols))
+    }
+}
+
 macro_rules! impl_bin_op {
     ($Trait: ident, $trait: ident, $name: ident, $scalar: ident, $op: tt) => (
         impl<'out, 'a, 'b, T> Matrix<T>
@@ -402,7 +758,7 @@ macro_rules! impl_bin_op {
         }
 
         impl<'a, 'b, T> $Trait<&'b Matrix<T>> for  &'a Matrix<T>
-            where T: 'a + 'b,
+            where T: 'a + 'b + Zero,
                   &'a T: $Trait<&'b T, Output = T>,
         {
             type Output = Matrix<T>;
@@ -416,7 +772,7 @@ macro_rules! impl_bin_op {
         }
 
         impl<'a, 'b, T> $Trait<&'b T> for  &'a Matrix<T>
-            where T: 'a + 'b,
+            where T: 'a + 'b + Zero,
                   &'a T: $Trait<&'b T, Output = T>,
         {
             type Output = Matrix<T>;
@@ -437,9 +793,30 @@ impl_bin_op!(Sub, sub, sub, ssub, -);
 
 #[cfg(test)]
 mod test {
+    use alloc::string::String;
+
     use super::*;
 
 
+    /// integers modulo 7, an element type with no `Default` or conversions
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Mod7(u8);
+
+    impl Zero for Mod7 {
+        fn zero() -> Self { Mod7(0) }
+    }
+    impl One for Mod7 {
+        fn one() -> Self { Mod7(1) }
+    }
+    impl AddAssign for Mod7 {
+        fn add_assign(&mut self, other: Mod7) { self.0 = (self.0 + other.0) % 7; }
+    }
+    impl<'b> Mul<&'b Mod7> for &Mod7 {
+        type Output = Mod7;
+        fn mul(self, other: &'b Mod7) -> Mod7 { Mod7(self.0 * other.0 % 7) }
+    }
+
+
     #[test]
     fn test_mul_matrix_matrix() {
         let a: Matrix<usize> = Matrix::identity(3, 3);
@@ -483,4 +860,204 @@ mod test {
         assert_eq!(b[0][0], 0.5);
         assert_eq!(b[1][1], 0.5);
     }
+    #[test]
+    fn test_pow_matrix() {
+        let fib: Matrix<u64> = Matrix::from([[1, 1], [1, 0]]);
+        assert_eq!(fib.pow(0), Matrix::identity(2, 2));
+        assert_eq!(fib.pow(1), fib);
+        assert_eq!(fib.pow(10), Matrix::from([[89, 55], [55, 34]]));
+        assert_eq!(fib.pow(90)[0][1], 2880067194370816120);
+
+        // x -> 3x + 1 mod 7 has period 6 in homogeneous coordinates
+        let affine: Matrix<Mod7> = Matrix::from([[Mod7(3), Mod7(1)], [Mod7(0), Mod7(1)]]);
+        assert_eq!(affine.pow(6), Matrix::identity(2, 2));
+        assert_eq!(affine.pow(2), Matrix::from([[Mod7(2), Mod7(4)], [Mod7(0), Mod7(1)]]));
+        assert_eq!(affine.pow(u32::MAX), affine.pow(u32::MAX % 6));
+
+        assert_eq!(Matrix::<u64>::zeroed(0, 0).pow(3), Matrix::zeroed(0, 0));
+    }
+    #[test]
+    #[should_panic(expected = "can not raise a 2x3 matrix to a power")]
+    fn test_pow_matrix_not_square() {
+        Matrix::<u64>::zeroed(2, 3).pow(2);
+    }
+    #[test]
+    fn test_reshape_flatten_matrix() {
+        let a: Matrix<usize> = Matrix::from_fn(2, 3, |i, j| i * 3 + j);
+        assert_eq!(&*a.flatten(), &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(a.clone().reshape(3, 2), Matrix::from_row_slice(3, 2, &[0, 1, 2, 3, 4, 5]));
+        assert_eq!(a.clone().reshape(1, 6).reshape(2, 3), a);
+        assert_eq!(Matrix::<usize>::zeroed(0, 4).reshape(2, 0), Matrix::zeroed(2, 0));
+    }
+    #[test]
+    #[should_panic(expected = "can not reshape a 2x3 matrix into 4x2")]
+    fn test_reshape_matrix_wrong_size() {
+        Matrix::<usize>::zeroed(2, 3).reshape(4, 2);
+    }
+    #[test]
+    #[should_panic(expected = "can not reshape a 0x4 matrix into")]
+    fn test_reshape_matrix_overflow() {
+        // the product wraps to zero, the element count of the empty matrix
+        Matrix::<usize>::zeroed(0, 4).reshape(usize::MAX / 2 + 1, 2);
+    }
+    #[test]
+    fn test_swap_rows_columns_matrix() {
+        let mut a: Matrix<usize> = Matrix::from_fn(2, 3, |i, j| i * 3 + j);
+        a.swap_rows(0, 1);
+        assert_eq!(a, Matrix::from_row_slice(2, 3, &[3, 4, 5, 0, 1, 2]));
+        a.swap_columns(0, 2);
+        assert_eq!(a, Matrix::from_row_slice(2, 3, &[5, 4, 3, 2, 1, 0]));
+        a.swap_columns(1, 1);
+        assert_eq!(a, Matrix::from_row_slice(2, 3, &[5, 4, 3, 2, 1, 0]));
+    }
+    #[test]
+    #[should_panic(expected = "column indices (0, 3) out of range for a 2x3 matrix")]
+    fn test_swap_columns_out_of_range() {
+        Matrix::<usize>::zeroed(2, 3).swap_columns(0, 3);
+    }
+    #[test]
+    fn test_from_fn_matrix() {
+        let a: Matrix<usize> = Matrix::from_fn(2, 3, |i, j| i * 3 + j);
+        assert_eq!(a, Matrix::from_row_slice(2, 3, &[0, 1, 2, 3, 4, 5]));
+        assert_eq!(a[1][2], 5);
+    }
+    #[test]
+    fn test_from_rows_columns_matrix() {
+        let rows = [Vector::from_slice(&[1, 2, 3]), Vector::from_slice(&[4, 5, 6])];
+        let a: Matrix<usize> = Matrix::from_rows(&rows);
+        let b: Matrix<usize> = Matrix::from_columns(&rows);
+        assert_eq!(a.rows(), 2);
+        assert_eq!(b.rows(), 3);
+        assert_eq!(b, a.transpose());
+    }
+    #[test]
+    fn test_from_diagonal_matrix() {
+        let a: Matrix<usize> = Matrix::from_diagonal(&Vector::from_slice(&[1, 1, 1]));
+        assert_eq!(a, Matrix::identity(3, 3));
+    }
+    #[test]
+    #[should_panic]
+    fn test_from_row_slice_wrong_length() {
+        let _: Matrix<usize> = Matrix::from_row_slice(2, 2, &[1, 2, 3]);
+    }
+    #[test]
+    fn test_non_pod_matrix() {
+        let a: Matrix<String> = Matrix::from_fn(2, 3, |i, j| alloc::format!("{}{}", i, j));
+        let b = a.transpose();
+        assert_eq!(b[2][1], "12");
+        assert_eq!(b.map(|x| x.len()).sum(), 12);
+
+        let c: Matrix<String> = Matrix::new(2, 2);
+        assert_eq!(c.clone()[1][1], "");
+    }
+    #[test]
+    fn test_get_matrix() {
+        let mut a: Matrix<usize> = Matrix::identity(2, 3);
+        assert_eq!(a.get(1, 1), Some(&1));
+        assert_eq!(a.get(2, 0), None);
+        assert_eq!(a.get(0, 3), None);
+
+        *a.get_mut(0, 2).unwrap() = 4;
+        a[(1, 0)] = 7;
+        assert_eq!(a[(0, 2)], 4);
+        assert_eq!(a[1][0], 7);
+        assert_eq!(unsafe { *a.get_unchecked(1, 1) }, 1);
+    }
+    #[test]
+    fn test_index_row_matrix() {
+        let mut a: Matrix<usize> = Matrix::identity(2, 3);
+        a[1].copy_from_slice(&[4, 5, 6]);
+        assert_eq!(&a[1], &[4, 5, 6]);
+        assert_eq!(a[0].len(), a.cols());
+        assert_eq!(unsafe { *a.get_unchecked(1, 2) }, 6);
+    }
+    #[test]
+    #[should_panic(expected = "index (2, 1) out of range for a 2x3 matrix")]
+    fn test_index_matrix_out_of_range() {
+        let a: Matrix<usize> = Matrix::identity(2, 3);
+        let _ = a[(2, 1)];
+    }
+    #[test]
+    fn test_empty_matrix() {
+        let a: Matrix<isize> = Matrix::zeroed(0, 3);
+        let b: Matrix<isize> = Matrix::zeroed(3, 0);
+        assert!(a.is_empty());
+        assert_eq!(a.transpose(), b);
+        assert_eq!(&a * &Matrix::identity(3, 3), a);
+        assert_eq!(&b * &a, Matrix::zeroed(3, 3));
+        assert_eq!(&a * &b, Matrix::zeroed(0, 0));
+        assert_eq!(a.sum(), 0);
+        assert_eq!(a.max(), None);
+        assert_eq!(a.iter().count(), 0);
+        assert_eq!(b.indexed_iter().count(), 0);
+    }
+    #[test]
+    fn test_empty_matrix_determinant() {
+        let a: Matrix<isize> = Matrix::identity(0, 0);
+        assert_eq!(a.determinant(), 1);
+        assert_eq!(a.inverse(), a);
+    }
+    #[test]
+    fn test_grow_matrix_rows() {
+        let mut a: Matrix<usize> = Matrix::zeroed(0, 0);
+        a.push_row(Vector::from_slice(&[1, 2]));
+        a.push_row(Vector::from_slice(&[5, 6]));
+        a.insert_row(1, Vector::from_slice(&[3, 4]));
+        assert_eq!(a, Matrix::from_row_slice(3, 2, &[1, 2, 3, 4, 5, 6]));
+
+        assert_eq!(&*a.remove_row(0), &[1, 2]);
+        assert_eq!(a, Matrix::from_row_slice(2, 2, &[3, 4, 5, 6]));
+    }
+    #[test]
+    fn test_grow_matrix_columns() {
+        let mut a: Matrix<usize> = Matrix::new(0, 0);
+        a.push_column(Vector::from_slice(&[1, 4]));
+        a.push_column(Vector::from_slice(&[3, 6]));
+        a.insert_column(1, Vector::from_slice(&[2, 5]));
+        assert_eq!(a, Matrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]));
+
+        assert_eq!(&*a.remove_column(2), &[3, 6]);
+        assert_eq!(a, Matrix::from_row_slice(2, 2, &[1, 2, 4, 5]));
+        assert_eq!(&*a.remove_column(0), &[1, 4]);
+        assert_eq!(&*a.remove_column(0), &[2, 5]);
+        assert_eq!(a, Matrix::zeroed(2, 0));
+    }
+    #[test]
+    #[should_panic(expected = "row has length 3, expected 2 for a 1x2 matrix")]
+    fn test_push_row_wrong_length() {
+        let mut a: Matrix<usize> = Matrix::zeroed(1, 2);
+        a.push_row(Vector::zeroed(3));
+    }
+    #[test]
+    fn test_map_matrix() {
+        let a: Matrix<isize> = Matrix::identity(2, 3);
+        let b: Matrix<f64> = a.map(|x| *x as f64 + 0.5);
+        assert_eq!(b[0][0], 1.5);
+        assert_eq!(b[1][2], 0.5);
+
+        let mut c = a.clone();
+        c.map_inplace(|x| *x *= 4);
+        assert_eq!(c, &a * &4);
+    }
+    #[test]
+    fn test_zip_matrix() {
+        let a: Matrix<usize> = Matrix::identity(2, 2);
+        let mut b: Matrix<usize> = &a * &3;
+        assert_eq!(a.zip_map(&b, |x, y| x + y), &a * &4);
+
+        b.zip_apply(&a, |x, y| *x -= *y);
+        assert_eq!(b, &a * &2);
+    }
+    #[test]
+    fn test_fold_matrix() {
+        let mut a: Matrix<isize> = Matrix::identity(2, 3);
+        a[0][2] = -4;
+        a[1][0] = 3;
+        assert_eq!(a.fold(0, |acc, x| acc + x * x), 27);
+        assert_eq!(a.sum(), 1);
+        assert_eq!(a.product(), 0);
+        assert_eq!(a.min(), Some(&-4));
+        assert_eq!(a.max(), Some(&3));
+        assert_eq!(a.argmax(), Some((1, 0)));
+    }
 }
@@ -0,0 +1,465 @@
+use alloc::vec::Vec;
+
+use super::float::Float;
+use super::matrix::Matrix;
+use super::structure::rounding_tolerance;
+
+
+/// `theta_m` from Higham (2005), the largest 1-norm for which the `[m/m]` Padé approximant
+/// of `exp` is accurate to double precision
+const EXP_THETA: [(usize, f64); 4] = [
+    (3, 1.495585217958292e-2),
+    (5, 2.53939833006323e-1),
+    (7, 9.504178996162932e-1),
+    (9, 2.097847961257068e0),
+];
+const EXP_THETA_13: f64 = 5.371920351148152e0;
+
+const EXP_PADE_3: [f64; 4] = [120.0, 60.0, 12.0, 1.0];
+const EXP_PADE_5: [f64; 6] = [30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0];
+const EXP_PADE_7: [f64; 8] = [17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0];
+const EXP_PADE_9: [f64; 10] = [
+    17643225600.0, 8821612800.0, 2075673600.0, 302702400.0, 30270240.0,
+    2162160.0, 110880.0, 3960.0, 90.0, 1.0,
+];
+const EXP_PADE_13: [f64; 14] = [
+    64764752532480000.0, 32382376266240000.0, 7771770303897600.0, 1187353796428800.0,
+    129060195264000.0, 10559470521600.0, 670442572800.0, 33522128640.0,
+    1323241920.0, 40840800.0, 960960.0, 16380.0, 182.0, 1.0,
+];
+
+/// nodes and weights of 8 point Gauss-Legendre quadrature on `[0, 1]`, evaluating the
+/// `[8/8]` Padé approximant of `log(I + X)` in partial fractions
+const LOG_GAUSS: [(f64, f64); 8] = [
+    (0.019855071751231856, 0.05061426814518813),
+    (0.10166676129318664, 0.11119051722668724),
+    (0.2372337950418355, 0.15685332293894363),
+    (0.4082826787521751, 0.181341891689181),
+    (0.591717321247825, 0.181341891689181),
+    (0.7627662049581645, 0.15685332293894363),
+    (0.8983332387068134, 0.11119051722668724),
+    (0.9801449282487682, 0.05061426814518813),
+];
+/// `log` takes square roots until `|A - I|_1` is at most this, where the `[8/8]` Padé
+/// approximant is accurate to double precision
+const LOG_THETA: f64 = 0.25;
+
+/// upper bound on square roots taken by `log` and Denman-Beavers iterations taken by `sqrt`
+const MAX_ITERATIONS: usize = 64;
+
+
+#[inline]
+fn assert_square<T>(op: &str, m: &Matrix<T>) {
+    assert!(m.rows() == m.cols(), "{} requires a square matrix, found {}x{}", op, m.rows(), m.cols());
+}
+
+/// `a * b` by the shared multiplication kernel, which needs no more of `T` than `Float`
+#[inline]
+fn mul<T: Float>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    let mut out = Matrix::zeroed(a.rows(), b.cols());
+    Matrix::mul_with(&mut out, a, b, |x, y| *x * *y);
+    out
+}
+
+/// `sum(c_k m_k)`, all matrices the same shape
+#[inline]
+fn combine<T: Float>(terms: &[(T, &Matrix<T>)]) -> Matrix<T> {
+    let (rows, cols) = (terms[0].1.rows(), terms[0].1.cols());
+    Matrix::from_fn(rows, cols, |i, j| terms.iter().fold(T::zero(), |sum, &(c, m)| sum + c * m[i][j]))
+}
+
+/// solves `a x = b` by LU decomposition with partial pivoting, `None` if `a` is singular
+pub(crate) fn solve<T: Float>(a: &Matrix<T>, b: &Matrix<T>) -> Option<Matrix<T>> {
+    let n = a.rows();
+    let mut lu = a.clone();
+    let mut x = b.clone();
+
+    for k in 0..n {
+        let pivot = (k..n).fold(k, |p, i| if lu[i][k].abs() > lu[p][k].abs() { i } else { p });
+        if lu[pivot][k] == T::zero() || !lu[pivot][k].is_finite() {
+            return None;
+        }
+        lu.swap_rows(k, pivot);
+        x.swap_rows(k, pivot);
+
+        for i in k + 1..n {
+            let factor = lu[i][k] / lu[k][k];
+            for j in k..n {
+                let d = factor * lu[k][j];
+                lu[i][j] -= d;
+            }
+            for j in 0..x.cols() {
+                let d = factor * x[k][j];
+                x[i][j] -= d;
+            }
+        }
+    }
+    for k in (0..n).rev() {
+        for j in 0..x.cols() {
+            let sum = (k + 1..n).fold(x[k][j], |sum, i| sum - lu[k][i] * x[i][j]);
+            x[k][j] = sum / lu[k][k];
+        }
+    }
+
+    if x.iter().all(|x| x.is_finite()) { Some(x) } else { None }
+}
+
+/// `exp(a)` by the `[m/m]` Padé approximant, `coefficients` holds its `m + 1` coefficients,
+/// `None` if rounding leaves the denominator singular
+fn exp_pade<T: Float>(a: &Matrix<T>, coefficients: &[f64]) -> Option<Matrix<T>> {
+    let n = a.rows();
+    let b = |k: usize| T::from_f64(coefficients[k]);
+    let identity = Matrix::identity(n, n);
+    let a2 = mul(a, a);
+
+    let (u, v) = if coefficients.len() == 14 {
+        let a4 = mul(&a2, &a2);
+        let a6 = mul(&a4, &a2);
+
+        let u = combine(&[(b(13), &a6), (b(11), &a4), (b(9), &a2)]);
+        let u = combine(&[(T::one(), &mul(&a6, &u)), (b(7), &a6), (b(5), &a4), (b(3), &a2), (b(1), &identity)]);
+        let v = combine(&[(b(12), &a6), (b(10), &a4), (b(8), &a2)]);
+        let v = combine(&[(T::one(), &mul(&a6, &v)), (b(6), &a6), (b(4), &a4), (b(2), &a2), (b(0), &identity)]);
+        (mul(a, &u), v)
+    } else {
+        // u = a (b_1 I + b_3 a^2 + ...), v = b_0 I + b_2 a^2 + ...
+        let mut power = identity.clone();
+        let mut u = combine(&[(b(1), &identity)]);
+        let mut v = combine(&[(b(0), &identity)]);
+
+        for k in (2..coefficients.len()).step_by(2) {
+            power = mul(&power, &a2);
+            v = combine(&[(T::one(), &v), (b(k), &power)]);
+            if k + 1 < coefficients.len() {
+                u = combine(&[(T::one(), &u), (b(k + 1), &power)]);
+            }
+        }
+        (mul(a, &u), v)
+    };
+
+    let p = combine(&[(T::one(), &v), (T::one(), &u)]);
+    let q = combine(&[(T::one(), &v), (-T::one(), &u)]);
+    solve(&q, &p)
+}
+
+/// the principal square root by the product form of the Denman-Beavers iteration, `None` if
+/// it breaks down on a singular iterate or does not converge
+fn sqrt_denman_beavers<T: Float>(a: &Matrix<T>) -> Option<Matrix<T>> {
+    let n = a.rows();
+    let identity: Matrix<T> = Matrix::identity(n, n);
+    let half = T::one() / (T::one() + T::one());
+    let quarter = half * half;
+    let tolerance = T::epsilon() * T::from_f64(10.0 * n as f64);
+
+    let mut m = a.clone();
+    let mut y = a.clone();
+    let mut error = T::infinity();
+
+    for _ in 0..MAX_ITERATIONS {
+        let m_inv = solve(&m, &identity)?;
+
+        // y = y (I + m^-1) / 2, m = I / 2 + (m + m^-1) / 4
+        y = mul(&y, &combine(&[(half, &identity), (half, &m_inv)]));
+        m = combine(&[(half, &identity), (quarter, &m), (quarter, &m_inv)]);
+
+        let next = combine(&[(T::one(), &m), (-T::one(), &identity)]).norm_1();
+        if next <= tolerance || (next >= error && error <= T::epsilon().sqrt()) {
+            return Some(y);
+        }
+        error = next;
+    }
+    None
+}
+
+/// the eigenvalues of a symmetric matrix and the orthogonal matrix whose columns are the
+/// matching eigenvectors, found with cyclic Jacobi rotations
+fn symmetric_eigen<T: Float>(m: &Matrix<T>) -> (Vec<T>, Matrix<T>) {
+    let n = m.rows();
+    let two = T::one() + T::one();
+    let mut a = m.clone();
+    let mut v: Matrix<T> = Matrix::identity(n, n);
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q].abs() <= T::epsilon() * (a[p][p].abs() * a[q][q].abs()).sqrt() ||
+                    a[p][q] == T::zero()
+                {
+                    continue;
+                }
+                rotated = true;
+
+                // zero a[p][q] with a = J^T a J, J rotating the (p, q) plane
+                let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                let t = T::one() / (theta.abs() + T::one().hypot(theta));
+                let t = if theta < T::zero() { -t } else { t };
+                let c = T::one() / T::one().hypot(t);
+                let s = c * t;
+
+                for k in 0..n {
+                    let (x, y) = (a[k][p], a[k][q]);
+                    a[k][p] = c * x - s * y;
+                    a[k][q] = s * x + c * y;
+
+                    let (x, y) = (v[k][p], v[k][q]);
+                    v[k][p] = c * x - s * y;
+                    v[k][q] = s * x + c * y;
+                }
+                for k in 0..n {
+                    let (x, y) = (a[p][k], a[q][k]);
+                    a[p][k] = c * x - s * y;
+                    a[q][k] = s * x + c * y;
+                }
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    ((0..n).map(|i| a[i][i]).collect(), v)
+}
+
+
+impl<T: Float> Matrix<T> {
+    /// the matrix exponential by scaling and squaring with a Padé approximant, following
+    /// Higham (2005), panics if the matrix is not square
+    pub fn exp(&self) -> Matrix<T> {
+        assert_square("exp", self);
+
+        let norm = self.norm_1();
+        if !norm.is_finite() {
+            return self.map(|_| T::nan());
+        }
+        if let Some(&(m, _)) = EXP_THETA.iter().find(|&&(_, theta)| norm <= T::from_f64(theta)) {
+            let coefficients: &[f64] = match m {
+                3 => &EXP_PADE_3,
+                5 => &EXP_PADE_5,
+                7 => &EXP_PADE_7,
+                _ => &EXP_PADE_9,
+            };
+            if let Some(out) = exp_pade(self, coefficients) {
+                return out;
+            }
+        }
+
+        // scale so the norm is at most theta_13, then square the result back
+        let mut squarings = 0;
+        let mut scale = T::one();
+        let theta = T::from_f64(EXP_THETA_13);
+        while norm * scale > theta {
+            scale /= T::one() + T::one();
+            squarings += 1;
+        }
+
+        // the denominator is well conditioned at these norms, should rounding still leave it
+        // singular keep scaling down
+        for _ in 0..MAX_ITERATIONS {
+            if let Some(mut out) = exp_pade(&self.map(|x| *x * scale), &EXP_PADE_13) {
+                for _ in 0..squarings {
+                    out = mul(&out, &out);
+                }
+                return out;
+            }
+            scale /= T::one() + T::one();
+            squarings += 1;
+        }
+        self.map(|_| T::nan())
+    }
+
+    /// the principal logarithm by inverse scaling and squaring, taking square roots until the
+    /// matrix is close to the identity and then using a Padé approximant, `None` if the matrix
+    /// is singular or has eigenvalues on the negative real axis, where no real principal
+    /// logarithm exists, panics if the matrix is not square
+    pub fn log(&self) -> Option<Matrix<T>> {
+        assert_square("log", self);
+
+        let n = self.rows();
+        let identity: Matrix<T> = Matrix::identity(n, n);
+        let theta = T::from_f64(LOG_THETA);
+        let mut a = self.clone();
+        let mut scale = T::one();
+
+        for _ in 0..MAX_ITERATIONS {
+            let x = combine(&[(T::one(), &a), (-T::one(), &identity)]);
+
+            if x.norm_1() <= theta {
+                // log(I + x) = sum(w_j x (I + t_j x)^-1)
+                let mut out = Matrix::zeroed(n, n);
+                for &(t, w) in LOG_GAUSS.iter() {
+                    let denominator = combine(&[(T::one(), &identity), (T::from_f64(t), &x)]);
+                    let term = solve(&denominator, &x)?;
+                    out = combine(&[(T::one(), &out), (T::from_f64(w), &term)]);
+                }
+                return Some(out.map(|x| *x * scale));
+            }
+
+            a = sqrt_denman_beavers(&a)?;
+            scale = scale + scale;
+        }
+        None
+    }
+
+    /// the principal square root by the Denman-Beavers iteration, `None` if the matrix is
+    /// singular or has eigenvalues on the negative real axis, panics if the matrix is not square
+    #[inline]
+    pub fn sqrt(&self) -> Option<Matrix<T>> {
+        assert_square("sqrt", self);
+        sqrt_denman_beavers(self)
+    }
+
+    /// raises a symmetric matrix to a real power through its eigendecomposition, `None` if
+    /// the matrix is not square and symmetric or an eigenvalue raised to `p` is not finite,
+    /// as for a negative eigenvalue and fractional `p` or a zero eigenvalue and negative `p`
+    pub fn powf(&self, p: T) -> Option<Matrix<T>> {
+        if !self.is_symmetric(rounding_tolerance(self)) {
+            return None;
+        }
+
+        let (values, vectors) = symmetric_eigen(self);
+        let mut powers = Vec::with_capacity(values.len());
+        for value in values {
+            let power = value.powf(p);
+            if !power.is_finite() {
+                return None;
+            }
+            powers.push(power);
+        }
+
+        // v diag(lambda^p) v^T
+        let n = self.rows();
+        Some(Matrix::from_fn(n, n, |i, j| {
+            (0..n).fold(T::zero(), |sum, k| sum + vectors[i][k] * powers[k] * vectors[j][k])
+        }))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+
+    fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>, tolerance: f64) {
+        assert_eq!((a.rows(), a.cols()), (b.rows(), b.cols()));
+        assert!(a.iter().zip(b.iter()).all(|(x, y)| (x - y).abs() <= tolerance * (1.0 + y.abs())),
+            "{:?} != {:?}", a, b);
+    }
+    fn rotation(angle: f64) -> Matrix<f64> {
+        Matrix::from([[angle.cos(), -angle.sin()], [angle.sin(), angle.cos()]])
+    }
+    fn generator(angle: f64) -> Matrix<f64> {
+        Matrix::from([[0.0, -angle], [angle, 0.0]])
+    }
+
+
+    #[test]
+    fn test_exp_diagonal() {
+        for &scale in &[1e-3, 0.1, 1.0, 3.0, 20.0] {
+            let d: Matrix<f64> = Matrix::from([[scale, 0.0, 0.0], [0.0, -2.0 * scale, 0.0], [0.0, 0.0, 0.0]]);
+            let e = Matrix::from([[scale.exp(), 0.0, 0.0], [0.0, (-2.0 * scale).exp(), 0.0], [0.0, 0.0, 1.0]]);
+            assert_close(&d.exp(), &e, 1e-13);
+        }
+        assert_eq!(Matrix::<f64>::zeroed(0, 0).exp().rows(), 0);
+
+        // overflows rather than panicking
+        let huge: Matrix<f64> = Matrix::from([[1e307, -1e307], [1e307, 1e307]]);
+        assert!(!huge.exp().iter().all(|x| x.is_finite()));
+    }
+    #[test]
+    fn test_exp_nilpotent() {
+        let n: Matrix<f64> = Matrix::from([[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]]);
+        assert_close(&n.exp(), &Matrix::from([[1.0, 1.0, 0.5], [0.0, 1.0, 1.0], [0.0, 0.0, 1.0]]), 1e-15);
+
+        let big = n.map(|x| x * 40.0);
+        assert_close(&big.exp(), &Matrix::from([[1.0, 40.0, 800.0], [0.0, 1.0, 40.0], [0.0, 0.0, 1.0]]), 1e-13);
+    }
+    #[test]
+    fn test_exp_rotation_generator() {
+        for &angle in &[0.01, 1.0, 3.0, 10.0, -25.0] {
+            assert_close(&generator(angle).exp(), &rotation(angle), 1e-13);
+        }
+
+        let g: Matrix<f32> = Matrix::from([[0.0, -1.0], [1.0, 0.0]]);
+        let e = g.exp();
+        assert!((e[0][0] - 1f32.cos()).abs() < 1e-6 && (e[1][0] - 1f32.sin()).abs() < 1e-6);
+    }
+    #[test]
+    fn test_log() {
+        let d: Matrix<f64> = Matrix::from([[1f64.exp(), 0.0], [0.0, 2f64.exp()]]);
+        assert_close(&d.log().unwrap(), &Matrix::from([[1.0, 0.0], [0.0, 2.0]]), 1e-13);
+
+        for &angle in &[0.1, 1.0, 3.0] {
+            assert_close(&rotation(angle).log().unwrap(), &generator(angle), 1e-12);
+        }
+
+        // log(I + N) = N - N^2 / 2 for nilpotent N with N^3 = 0
+        let unipotent: Matrix<f64> = Matrix::from([[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [0.0, 0.0, 1.0]]);
+        assert_close(&unipotent.log().unwrap(), &Matrix::from([[0.0, 2.0, -1.0], [0.0, 0.0, 4.0], [0.0, 0.0, 0.0]]), 1e-12);
+
+        let a: Matrix<f64> = Matrix::from([[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]]);
+        assert_close(&a.log().unwrap().exp(), &a, 1e-12);
+    }
+    #[test]
+    fn test_log_undefined() {
+        assert!(Matrix::<f64>::from([[-1.0]]).log().is_none());
+        assert!(Matrix::<f64>::from([[1.0, 2.0], [2.0, 4.0]]).log().is_none());
+        assert!(rotation(core::f64::consts::PI).log().is_none());
+    }
+    #[test]
+    fn test_sqrt() {
+        let d: Matrix<f64> = Matrix::from([[4.0, 0.0], [0.0, 9.0]]);
+        assert_close(&d.sqrt().unwrap(), &Matrix::from([[2.0, 0.0], [0.0, 3.0]]), 1e-15);
+
+        for &angle in &[0.5, 2.0, 3.0] {
+            assert_close(&rotation(angle).sqrt().unwrap(), &rotation(angle / 2.0), 1e-13);
+        }
+
+        // (I + N)^(1/2) = I + N / 2 - N^2 / 8 for nilpotent N with N^3 = 0
+        let unipotent: Matrix<f64> = Matrix::from([[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [0.0, 0.0, 1.0]]);
+        assert_close(&unipotent.sqrt().unwrap(), &Matrix::from([[1.0, 1.0, 0.5], [0.0, 1.0, 2.0], [0.0, 0.0, 1.0]]), 1e-13);
+
+        let a: Matrix<f64> = Matrix::from([[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]]);
+        let root = a.sqrt().unwrap();
+        assert_close(&(&root * &root), &a, 1e-13);
+
+        assert!(Matrix::<f64>::from([[-1.0]]).sqrt().is_none());
+    }
+    #[test]
+    fn test_powf() {
+        let d: Matrix<f64> = Matrix::from([[4.0, 0.0], [0.0, 9.0]]);
+        assert_close(&d.powf(0.5).unwrap(), &Matrix::from([[2.0, 0.0], [0.0, 3.0]]), 1e-15);
+        assert_close(&d.powf(-1.0).unwrap(), &Matrix::from([[0.25, 0.0], [0.0, 1.0 / 9.0]]), 1e-15);
+
+        let a: Matrix<f64> = Matrix::from([[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]]);
+        assert_close(&a.powf(2.0).unwrap(), &(&a * &a), 1e-13);
+        assert_close(&a.powf(0.5).unwrap(), &a.sqrt().unwrap(), 1e-13);
+        assert_close(&a.powf(-1.0).unwrap(), &a.inverse(), 1e-13);
+        assert_close(&a.powf(0.0).unwrap(), &Matrix::identity(3, 3), 1e-13);
+
+        // eigenvalues 3 and -1, so integer powers are defined but square roots are not
+        let b: Matrix<f64> = Matrix::from([[1.0, 2.0], [2.0, 1.0]]);
+        assert_close(&b.powf(3.0).unwrap(), &b.pow(3), 1e-13);
+        assert!(b.powf(0.5).is_none());
+
+        assert!(Matrix::<f64>::from([[1.0, 2.0], [0.0, 1.0]]).powf(2.0).is_none());
+        assert!(Matrix::<f64>::zeroed(2, 3).powf(2.0).is_none());
+    }
+    #[test]
+    fn test_float_bound_only() {
+        fn roots<T: Float>(m: &Matrix<T>) -> (Matrix<T>, Option<Matrix<T>>, Option<Matrix<T>>) {
+            (m.exp(), m.sqrt(), m.powf(T::from_f64(0.5)))
+        }
+        let (e, root, half) = roots(&Matrix::<f64>::from([[4.0, 0.0], [0.0, 9.0]]));
+        assert_close(&e, &Matrix::from([[4f64.exp(), 0.0], [0.0, 9f64.exp()]]), 1e-13);
+        assert_close(&root.unwrap(), &half.unwrap(), 1e-13);
+    }
+    #[test]
+    #[should_panic]
+    fn test_exp_not_square() {
+        Matrix::<f64>::zeroed(2, 3).exp();
+    }
+}
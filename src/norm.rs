@@ -0,0 +1,272 @@
+use alloc::vec::Vec;
+
+use super::float::Float;
+use super::matrix::Matrix;
+use super::vector::Vector;
+
+
+/// upper bound on the Jacobi sweeps used for singular values, they converge quadratically
+const MAX_SWEEPS: usize = 64;
+
+
+/// accumulates a sum of squares as `scale^2 * ssq` so that it can not overflow or underflow
+struct SumSquares<T> {
+    scale: T,
+    ssq: T,
+    nan: bool,
+}
+
+impl<T: Float> SumSquares<T> {
+    #[inline(always)]
+    fn new() -> Self {
+        SumSquares {
+            scale: T::zero(),
+            ssq: T::one(),
+            nan: false,
+        }
+    }
+    #[inline]
+    fn add(&mut self, x: T) {
+        let a = x.abs();
+
+        if a.is_nan() {
+            self.nan = true;
+        } else if self.scale < a {
+            let r = self.scale / a;
+            self.ssq = T::one() + self.ssq * r * r;
+            self.scale = a;
+        } else if a != T::zero() && self.scale.is_finite() {
+            let r = a / self.scale;
+            self.ssq += r * r;
+        }
+    }
+    #[inline]
+    fn sqrt(&self) -> T {
+        if self.nan {
+            T::nan()
+        } else if !self.scale.is_finite() {
+            self.scale
+        } else {
+            self.scale * self.ssq.sqrt()
+        }
+    }
+}
+
+#[inline]
+fn norm_l2<T: Float, I: Iterator<Item = T>>(iter: I) -> T {
+    let mut sum = SumSquares::new();
+    for x in iter {
+        sum.add(x);
+    }
+    sum.sqrt()
+}
+
+/// the largest absolute value, `NaN` if any value is `NaN`
+#[inline]
+fn max_abs<T: Float, I: Iterator<Item = T>>(iter: I) -> T {
+    iter.fold(T::zero(), |max, x| {
+        let a = x.abs();
+        if a > max || a.is_nan() { a } else { max }
+    })
+}
+
+#[inline]
+fn sum_abs<'a, T: 'a + Float, I: Iterator<Item = &'a T>>(iter: I) -> T {
+    iter.fold(T::zero(), |sum, x| sum + x.abs())
+}
+
+/// the singular values of `m` in no particular order, found with one-sided Jacobi rotations
+/// that orthogonalize the columns of `m` or its transpose, whichever has fewer
+fn singular_values<T: Float>(m: &Matrix<T>) -> Vec<T> {
+    let scale = max_abs(m.iter().cloned());
+    if scale == T::zero() || !scale.is_finite() {
+        return (0..m.rows().min(m.cols())).map(|_| scale).collect();
+    }
+
+    let mut cols: Vec<Vec<T>> = if m.cols() <= m.rows() {
+        m.column_iter().map(|col| col.iter().map(|x| *x / scale).collect()).collect()
+    } else {
+        m.row_iter().map(|row| row.iter().map(|x| *x / scale).collect()).collect()
+    };
+
+    let two = T::one() + T::one();
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for q in 1..cols.len() {
+            let (left, right) = cols.split_at_mut(q);
+            let cq = &mut right[0];
+
+            for cp in left.iter_mut() {
+                let (mut alpha, mut beta, mut gamma) = (T::zero(), T::zero(), T::zero());
+                for (x, y) in cp.iter().zip(cq.iter()) {
+                    alpha += *x * *x;
+                    beta += *y * *y;
+                    gamma += *x * *y;
+                }
+                if gamma.abs() <= T::epsilon() * alpha.sqrt() * beta.sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = T::one() / (zeta.abs() + T::one().hypot(zeta));
+                let t = if zeta < T::zero() { -t } else { t };
+                let c = T::one() / T::one().hypot(t);
+                let s = c * t;
+
+                for (x, y) in cp.iter_mut().zip(cq.iter_mut()) {
+                    let (a, b) = (*x, *y);
+                    *x = c * a - s * b;
+                    *y = s * a + c * b;
+                }
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    cols.into_iter().map(|col| scale * norm_l2(col.into_iter())).collect()
+}
+
+
+impl<T: Float> Vector<T> {
+    /// the sum of absolute values
+    #[inline]
+    pub fn norm_l1(&self) -> T {
+        sum_abs(self.iter())
+    }
+    /// the euclidean length, scaled as it is summed so it only overflows if the result does
+    #[inline]
+    pub fn norm_l2(&self) -> T {
+        norm_l2(self.iter().cloned())
+    }
+    /// the largest absolute value
+    #[inline]
+    pub fn norm_inf(&self) -> T {
+        max_abs(self.iter().cloned())
+    }
+    /// `sum(|x|^p)^(1/p)`, panics unless `p` is positive
+    #[inline]
+    pub fn norm_p(&self, p: T) -> T {
+        assert!(p > T::zero(), "p-norm requires a positive p");
+
+        let max = self.norm_inf();
+        if p == T::infinity() || max == T::zero() || !max.is_finite() {
+            max
+        } else {
+            let sum = self.iter().fold(T::zero(), |sum, x| sum + (x.abs() / max).powf(p));
+            max * sum.powf(T::one() / p)
+        }
+    }
+
+    /// returns the vector scaled to unit length, or `None` if its length is zero or not finite
+    #[inline]
+    pub fn normalize(&self) -> Option<Vector<T>> {
+        let norm = self.norm_l2();
+
+        if norm > T::zero() && norm.is_finite() {
+            Some(self.map(|x| *x / norm))
+        } else {
+            None
+        }
+    }
+    /// the euclidean distance to `other`
+    #[inline]
+    pub fn distance(&self, other: &Vector<T>) -> T {
+        assert!(self.len() == other.len(), "a.distance(b), a's length does not match b's length");
+        norm_l2(self.iter().zip(other.iter()).map(|(a, b)| *a - *b))
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// the square root of the sum of squares, scaled as it is summed
+    #[inline]
+    pub fn norm_frobenius(&self) -> T {
+        norm_l2(self.iter().cloned())
+    }
+    /// the largest absolute column sum
+    #[inline]
+    pub fn norm_1(&self) -> T {
+        max_abs(self.column_iter().map(|col| sum_abs(col.iter())))
+    }
+    /// the largest absolute row sum
+    #[inline]
+    pub fn norm_inf(&self) -> T {
+        max_abs(self.row_iter().map(|row| sum_abs(row.iter())))
+    }
+    /// the largest absolute element
+    #[inline]
+    pub fn norm_max(&self) -> T {
+        max_abs(self.iter().cloned())
+    }
+    /// the sum of the singular values
+    #[inline]
+    pub fn norm_nuclear(&self) -> T {
+        singular_values(self).into_iter().fold(T::zero(), |sum, x| sum + x)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+
+    #[test]
+    fn test_vector_norms() {
+        let v: Vector<f64> = Vector::from([3.0, -4.0]);
+        assert_eq!(v.norm_l1(), 7.0);
+        assert_eq!(v.norm_l2(), 5.0);
+        assert_eq!(v.norm_inf(), 4.0);
+        assert!((v.norm_p(3.0) - 91f64.powf(1.0 / 3.0)).abs() < 1e-12);
+        assert!((v.norm_p(2.0) - 5.0).abs() < 1e-12);
+        assert_eq!(v.norm_p(f64::INFINITY), 4.0);
+        assert_eq!(Vector::<f32>::new(0).norm_l2(), 0.0);
+    }
+    #[test]
+    fn test_vector_norm_l2_scaling() {
+        let big: Vector<f64> = Vector::from([3e300, 4e300]);
+        assert!((big.norm_l2() / 5e300 - 1.0).abs() < 1e-15);
+        assert!((big.norm_p(3.0) / 91f64.powf(1.0 / 3.0) / 1e300 - 1.0).abs() < 1e-12);
+
+        let small: Vector<f32> = Vector::from([3e-30, 4e-30]);
+        assert!((small.norm_l2() / 5e-30 - 1.0).abs() < 1e-6);
+
+        assert_eq!(Vector::from([1.0, f64::INFINITY, f64::INFINITY]).norm_l2(), f64::INFINITY);
+        assert!(Vector::from([f64::INFINITY, f64::NAN]).norm_l2().is_nan());
+    }
+    #[test]
+    fn test_normalize_distance() {
+        let v: Vector<f64> = Vector::from([0.0, 3.0, 4.0]);
+        assert_eq!(v.normalize().unwrap(), Vector::from([0.0, 0.6, 0.8]));
+        assert!(Vector::<f64>::zeroed(3).normalize().is_none());
+        assert_eq!(v.distance(&Vector::from([0.0, 0.0, 0.0])), 5.0);
+    }
+    #[test]
+    fn test_matrix_norms() {
+        let m: Matrix<f64> = Matrix::from([[1.0, -2.0], [3.0, 4.0]]);
+        assert_eq!(m.norm_frobenius(), 30f64.sqrt());
+        assert_eq!(m.norm_1(), 6.0);
+        assert_eq!(m.norm_inf(), 7.0);
+        assert_eq!(m.norm_max(), 4.0);
+        // for a 2x2 matrix the singular values sum to sqrt(|m|_F^2 + 2 |det m|)
+        assert!((m.norm_nuclear() - 50f64.sqrt()).abs() < 1e-12);
+    }
+    #[test]
+    fn test_matrix_norm_nuclear() {
+        let m: Matrix<f64> = Matrix::from([[0.0, 3.0, 0.0], [-4.0, 0.0, 0.0]]);
+        assert!((m.norm_nuclear() - 7.0).abs() < 1e-12);
+        assert!((m.transpose().norm_nuclear() - 7.0).abs() < 1e-12);
+
+        // a rank one matrix has a single non-zero singular value, |u| |v|
+        let m: Matrix<f32> = Matrix::from_fn(4, 3, |i, j| (i + 1) as f32 * (j as f32 - 1.5));
+        let expected = 30f32.sqrt() * 2.75f32.sqrt();
+        assert!((m.norm_nuclear() - expected).abs() < 1e-4 * expected);
+
+        assert_eq!(Matrix::<f64>::zeroed(3, 2).norm_nuclear(), 0.0);
+        assert_eq!(Matrix::<f64>::identity(5, 5).norm_nuclear(), 5.0);
+    }
+}
@@ -0,0 +1,212 @@
+use core::ops::Index;
+
+use alloc::vec::Vec;
+
+use one::One;
+use zero::Zero;
+
+use super::matrix::Matrix;
+
+
+/// a permutation of `0..len`, applied by gathering so `m.permute_rows(&p)` has row `p[i]` of
+/// `m` as its row `i`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Permutation {
+    indices: Vec<usize>,
+}
+
+impl Permutation {
+    /// the permutation that leaves `0..len` in place
+    #[inline]
+    pub fn identity(len: usize) -> Self {
+        Permutation {
+            indices: (0..len).collect(),
+        }
+    }
+    /// builds a permutation from the image of each index, panics unless `indices` holds each of
+    /// `0..indices.len()` exactly once
+    #[inline]
+    pub fn new(indices: Vec<usize>) -> Self {
+        let mut seen = alloc::vec![false; indices.len()];
+        for &i in indices.iter() {
+            assert!(i < seen.len() && !seen[i], "{:?} is not a permutation of 0..{}", indices, seen.len());
+            seen[i] = true;
+        }
+        Permutation {
+            indices: indices,
+        }
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize { self.indices.len() }
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.indices.is_empty() }
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[usize] { &self.indices }
+
+    /// exchanges the images of `i` and `j`
+    #[inline]
+    pub fn swap(&mut self, i: usize, j: usize) {
+        self.indices.swap(i, j);
+    }
+
+    /// the permutation `i -> self[other[i]]`, so permuting by `self` and then by `other` is the
+    /// same as permuting by `self.compose(&other)`
+    #[inline]
+    pub fn compose(&self, other: &Permutation) -> Permutation {
+        assert!(self.len() == other.len(),
+            "can not compose permutations of different lengths, {} and {}", self.len(), other.len());
+        Permutation {
+            indices: other.indices.iter().map(|&i| self.indices[i]).collect(),
+        }
+    }
+    /// the permutation that undoes `self`
+    #[inline]
+    pub fn inverse(&self) -> Permutation {
+        let mut indices = alloc::vec![0; self.len()];
+        for (i, &j) in self.indices.iter().enumerate() {
+            indices[j] = i;
+        }
+        Permutation {
+            indices: indices,
+        }
+    }
+
+    /// returns true if the permutation is a product of an even number of transpositions
+    #[inline]
+    pub fn is_even(&self) -> bool {
+        // a cycle of length k is k - 1 transpositions, so the parity is that of len - cycles
+        let mut seen = alloc::vec![false; self.len()];
+        let mut cycles = 0;
+
+        for start in 0..self.len() {
+            if !seen[start] {
+                cycles += 1;
+                let mut i = start;
+                while !seen[i] {
+                    seen[i] = true;
+                    i = self.indices[i];
+                }
+            }
+        }
+        (self.len() - cycles) & 1 == 0
+    }
+    /// `1` for even permutations and `-1` for odd ones, the determinant of the permutation
+    /// matrix
+    #[inline]
+    pub fn sign(&self) -> i32 {
+        if self.is_even() { 1 } else { -1 }
+    }
+
+    /// the permutation matrix `P` with `&P * &m == m.permute_rows(self)`
+    #[inline]
+    pub fn to_matrix<T: Zero + One>(&self) -> Matrix<T> {
+        Matrix::from_fn(self.len(), self.len(), |i, j| if self.indices[i] == j { T::one() } else { T::zero() })
+    }
+}
+
+impl Index<usize> for Permutation {
+    type Output = usize;
+
+    #[inline(always)]
+    fn index(&self, i: usize) -> &usize {
+        &self.indices[i]
+    }
+}
+
+
+impl<T> Matrix<T>
+    where T: Clone,
+{
+    /// reorders the rows so that row `i` of the result is row `p[i]` of `self`
+    #[inline]
+    pub fn permute_rows(&self, p: &Permutation) -> Matrix<T> {
+        assert!(p.len() == self.rows(),
+            "can not permute the rows of a {}x{} matrix by a permutation of length {}", self.rows(), self.cols(), p.len());
+        Matrix::from_fn(self.rows(), self.cols(), |i, j| self[p[i]][j].clone())
+    }
+    /// reorders the columns so that column `j` of the result is column `p[j]` of `self`
+    #[inline]
+    pub fn permute_columns(&self, p: &Permutation) -> Matrix<T> {
+        assert!(p.len() == self.cols(),
+            "can not permute the columns of a {}x{} matrix by a permutation of length {}", self.rows(), self.cols(), p.len());
+        Matrix::from_fn(self.rows(), self.cols(), |i, j| self[i][p[j]].clone())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+
+    use super::*;
+
+
+    #[test]
+    fn test_new_identity() {
+        let p = Permutation::new(vec![2, 0, 1]);
+        assert_eq!(p.len(), 3);
+        assert_eq!(p[0], 2);
+        assert_eq!(p.as_slice(), &[2, 0, 1]);
+        assert_eq!(Permutation::identity(3).as_slice(), &[0, 1, 2]);
+        assert!(Permutation::identity(0).is_empty());
+    }
+    #[test]
+    #[should_panic(expected = "[0, 2, 0] is not a permutation of 0..3")]
+    fn test_new_repeated_index() {
+        Permutation::new(vec![0, 2, 0]);
+    }
+    #[test]
+    #[should_panic(expected = "[0, 3] is not a permutation of 0..2")]
+    fn test_new_index_out_of_range() {
+        Permutation::new(vec![0, 3]);
+    }
+    #[test]
+    fn test_compose_inverse() {
+        let p = Permutation::new(vec![2, 0, 1, 3]);
+        let q = Permutation::new(vec![1, 0, 3, 2]);
+        assert_eq!(p.compose(&q).as_slice(), &[0, 2, 3, 1]);
+        assert_eq!(p.compose(&p.inverse()), Permutation::identity(4));
+        assert_eq!(p.inverse().compose(&p), Permutation::identity(4));
+        assert_eq!(p.inverse().inverse(), p);
+    }
+    #[test]
+    fn test_sign() {
+        assert_eq!(Permutation::identity(5).sign(), 1);
+        assert_eq!(Permutation::identity(0).sign(), 1);
+        assert_eq!(Permutation::new(vec![1, 0, 2]).sign(), -1);
+        assert_eq!(Permutation::new(vec![1, 2, 0]).sign(), 1);
+        assert_eq!(Permutation::new(vec![3, 2, 1, 0]).sign(), 1);
+
+        let mut p = Permutation::identity(6);
+        for i in 0..5 {
+            p.swap(i, i + 1);
+            assert_eq!(p.is_even(), i % 2 == 1);
+        }
+
+        // the sign is multiplicative and matches the determinant of the permutation matrix
+        let q = Permutation::new(vec![4, 0, 5, 1, 3, 2]);
+        assert_eq!(p.compose(&q).sign(), p.sign() * q.sign());
+        assert_eq!(q.to_matrix::<i32>().determinant(), q.sign());
+    }
+    #[test]
+    fn test_permute_rows_columns() {
+        let m: Matrix<usize> = Matrix::from_fn(3, 2, |i, j| i * 2 + j);
+        let p = Permutation::new(vec![2, 0, 1]);
+        let q = Permutation::new(vec![1, 0, 2]);
+
+        assert_eq!(m.permute_rows(&p), Matrix::from_row_slice(3, 2, &[4, 5, 0, 1, 2, 3]));
+        assert_eq!(&p.to_matrix::<usize>() * &m, m.permute_rows(&p));
+        assert_eq!(m.permute_rows(&p).permute_rows(&q), m.permute_rows(&p.compose(&q)));
+        assert_eq!(m.permute_rows(&p).permute_rows(&p.inverse()), m);
+
+        let swap = Permutation::new(vec![1, 0]);
+        assert_eq!(m.permute_columns(&swap), Matrix::from_row_slice(3, 2, &[1, 0, 3, 2, 5, 4]));
+        assert_eq!(m.permute_columns(&swap), m.transpose().permute_rows(&swap).transpose());
+    }
+    #[test]
+    #[should_panic(expected = "can not permute the rows of a 3x2 matrix by a permutation of length 2")]
+    fn test_permute_rows_wrong_length() {
+        Matrix::<usize>::zeroed(3, 2).permute_rows(&Permutation::identity(2));
+    }
+}
@@ -0,0 +1,138 @@
+use core::ops::*;
+
+use super::matrix::Matrix;
+use super::vector::Vector;
+
+
+#[inline]
+fn assert_same_shape<T>(op: &str, a: &Matrix<T>, b: &Matrix<T>) {
+    assert!(a.rows() == b.rows() && a.cols() == b.cols(),
+        "{} requires matrices of the same shape, found {}x{} and {}x{}", op, a.rows(), a.cols(), b.rows(), b.cols());
+}
+
+
+impl<T> Matrix<T>
+    where for<'a, 'b> &'a T: Mul<&'b T, Output = T>,
+{
+    /// the Kronecker product, the `(rows * other.rows) x (cols * other.cols)` block matrix
+    /// whose block `(i, j)` is `self[i][j] * other`
+    #[inline]
+    pub fn kronecker(&self, other: &Matrix<T>) -> Matrix<T> {
+        let (p, q) = (other.rows(), other.cols());
+        Matrix::from_fn(self.rows() * p, self.cols() * q, |i, j| &self[i / p][j / q] * &other[i % p][j % q])
+    }
+    /// the column-wise Kronecker product of matrices with the same number of columns, column
+    /// `j` of the result is the Kronecker product of column `j` of each
+    #[inline]
+    pub fn khatri_rao(&self, other: &Matrix<T>) -> Matrix<T> {
+        assert!(self.cols() == other.cols(),
+            "khatri_rao requires matrices with the same number of columns, found {} and {}", self.cols(), other.cols());
+
+        let p = other.rows();
+        Matrix::from_fn(self.rows() * p, self.cols(), |i, j| &self[i / p][j] * &other[i % p][j])
+    }
+    /// the elementwise product of matrices of the same shape
+    #[inline]
+    pub fn hadamard(&self, other: &Matrix<T>) -> Matrix<T> {
+        assert_same_shape("hadamard", self, other);
+        self.zip_map(other, |a, b| a * b)
+    }
+}
+
+impl<T> Matrix<T>
+    where for<'a, 'b> &'a T: Div<&'b T, Output = T>,
+{
+    /// the elementwise quotient of matrices of the same shape
+    #[inline]
+    pub fn hadamard_div(&self, other: &Matrix<T>) -> Matrix<T> {
+        assert_same_shape("hadamard_div", self, other);
+        self.zip_map(other, |a, b| a / b)
+    }
+}
+
+impl<T> Vector<T>
+    where for<'a, 'b> &'a T: Mul<&'b T, Output = T>,
+{
+    /// the outer product, the `len x other.len` matrix whose element `(i, j)` is
+    /// `self[i] * other[j]`
+    #[inline]
+    pub fn outer(&self, other: &Vector<T>) -> Matrix<T> {
+        Matrix::from_fn(self.len(), other.len(), |i, j| &self[i] * &other[j])
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+
+    #[test]
+    fn test_kronecker() {
+        let a: Matrix<i32> = Matrix::from([[1, 2], [3, 4]]);
+        let b: Matrix<i32> = Matrix::from([[0, 5], [6, 7]]);
+        assert_eq!(a.kronecker(&b), Matrix::from([
+            [0, 5, 0, 10],
+            [6, 7, 12, 14],
+            [0, 15, 0, 20],
+            [18, 21, 24, 28],
+        ]));
+
+        // the two qubit operator X (x) I flips the first qubit
+        let x: Matrix<i32> = Matrix::from([[0, 1], [1, 0]]);
+        let xi = x.kronecker(&Matrix::identity(2, 2));
+        assert_eq!(&xi * &Matrix::from([[1], [2], [3], [4]]), Matrix::from([[3], [4], [1], [2]]));
+
+        let c: Matrix<i32> = Matrix::from([[1, 2, 3]]);
+        assert_eq!((c.kronecker(&a).rows(), c.kronecker(&a).cols()), (2, 6));
+        assert_eq!(a.kronecker(&Matrix::zeroed(0, 3)), Matrix::zeroed(0, 6));
+    }
+    #[test]
+    fn test_khatri_rao() {
+        let a: Matrix<i32> = Matrix::from([[1, 2], [3, 4]]);
+        let b: Matrix<i32> = Matrix::from([[5, 6], [7, 8], [9, 10]]);
+        assert_eq!(a.khatri_rao(&b), Matrix::from([
+            [5, 12],
+            [7, 16],
+            [9, 20],
+            [15, 24],
+            [21, 32],
+            [27, 40],
+        ]));
+
+        // each column is the Kronecker product of the matching columns
+        let col = |m: &Matrix<i32>, j: usize| Matrix::from_fn(m.rows(), 1, |i, _| m[i][j]);
+        assert_eq!(col(&a.khatri_rao(&b), 1), col(&a, 1).kronecker(&col(&b, 1)));
+    }
+    #[test]
+    #[should_panic(expected = "khatri_rao requires matrices with the same number of columns, found 2 and 3")]
+    fn test_khatri_rao_wrong_cols() {
+        Matrix::<i32>::zeroed(2, 2).khatri_rao(&Matrix::zeroed(2, 3));
+    }
+    #[test]
+    fn test_hadamard() {
+        let a: Matrix<f64> = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b: Matrix<f64> = Matrix::from([[2.0, 2.0, 0.5], [-1.0, 0.0, 3.0]]);
+        assert_eq!(a.hadamard(&b), Matrix::from([[2.0, 4.0, 1.5], [-4.0, 0.0, 18.0]]));
+        assert_eq!(a.hadamard(&b).hadamard_div(&a), b);
+        assert_eq!(a.hadamard_div(&a), Matrix::from([[1.0; 3]; 2]));
+    }
+    #[test]
+    #[should_panic(expected = "hadamard requires matrices of the same shape, found 2x3 and 3x2")]
+    fn test_hadamard_wrong_shape() {
+        Matrix::<i32>::zeroed(2, 3).hadamard(&Matrix::zeroed(3, 2));
+    }
+    #[test]
+    fn test_outer() {
+        let u: Vector<i32> = Vector::from([1, 2, 3]);
+        let v: Vector<i32> = Vector::from([4, 5]);
+        assert_eq!(u.outer(&v), Matrix::from([[4, 5], [8, 10], [12, 15]]));
+        assert_eq!(u.outer(&v), v.outer(&u).transpose());
+
+        // a separable filter is the outer product of its row and column kernels
+        let smooth: Vector<i32> = Vector::from([1, 2, 1]);
+        let edge: Vector<i32> = Vector::from([-1, 0, 1]);
+        assert_eq!(smooth.outer(&edge), Matrix::from([[-1, 0, 1], [-2, 0, 2], [-1, 0, 1]]));
+        assert_eq!(Vector::<i32>::new(0).outer(&v), Matrix::zeroed(0, 2));
+    }
+}
@@ -0,0 +1,408 @@
+use core::f64::consts::PI;
+use core::ops::{Mul, Neg};
+
+use super::float::Float;
+use super::matrix::Matrix;
+use super::vector::Vector;
+
+
+/// a quaternion `w + xi + yj + zk`, unit quaternions represent 3D rotations
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion<T> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Quaternion<T> {
+    #[inline(always)]
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Quaternion {
+            w: w,
+            x: x,
+            y: y,
+            z: z,
+        }
+    }
+}
+
+/// wraps an angle into `(-pi, pi]`
+#[inline]
+fn wrap_angle<T: Float>(angle: T) -> T {
+    let pi = T::from_f64(PI);
+    let two_pi = pi + pi;
+
+    if angle > pi {
+        angle - two_pi
+    } else if angle <= -pi {
+        angle + two_pi
+    } else {
+        angle
+    }
+}
+
+impl<T: Float> Quaternion<T> {
+    /// the rotation that does nothing
+    #[inline(always)]
+    pub fn identity() -> Self {
+        Quaternion::new(T::one(), T::zero(), T::zero(), T::zero())
+    }
+
+    #[inline(always)]
+    pub fn conjugate(&self) -> Self {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+    #[inline(always)]
+    pub fn dot(&self, other: &Self) -> T {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+    #[inline]
+    pub fn norm(&self) -> T {
+        self.w.hypot(self.x).hypot(self.y.hypot(self.z))
+    }
+    /// returns the multiplicative inverse, or `None` for the zero quaternion
+    #[inline]
+    pub fn inverse(&self) -> Option<Self> {
+        let norm_sq = self.dot(self);
+
+        if norm_sq > T::zero() && norm_sq.is_finite() {
+            let c = self.conjugate();
+            Some(Quaternion::new(c.w / norm_sq, c.x / norm_sq, c.y / norm_sq, c.z / norm_sq))
+        } else {
+            None
+        }
+    }
+    /// returns the quaternion scaled to unit length, or `None` if its length is zero or not finite
+    #[inline]
+    pub fn normalize(&self) -> Option<Self> {
+        let norm = self.norm();
+
+        if norm > T::zero() && norm.is_finite() {
+            Some(Quaternion::new(self.w / norm, self.x / norm, self.y / norm, self.z / norm))
+        } else {
+            None
+        }
+    }
+
+    /// normalized linear interpolation along the shorter arc, from `self` at `t = 0` to
+    /// `other` at `t = 1`, cheaper than `slerp` but not constant speed
+    #[inline]
+    pub fn nlerp(&self, other: &Self, t: T) -> Self {
+        let other = if self.dot(other) < T::zero() { -*other } else { *other };
+        let s = T::one() - t;
+
+        Quaternion::new(
+            self.w * s + other.w * t,
+            self.x * s + other.x * t,
+            self.y * s + other.y * t,
+            self.z * s + other.z * t,
+        ).normalize().unwrap_or(*self)
+    }
+    /// spherical linear interpolation of unit quaternions along the shorter arc, from `self` at
+    /// `t = 0` to `other` at `t = 1`, rotating at constant speed
+    #[inline]
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let (other, d) = match self.dot(other) {
+            d if d < T::zero() => (-*other, -d),
+            d => (*other, d),
+        };
+        let angle = if d < T::one() { d.acos() } else { T::zero() };
+        let sin = angle.sin();
+
+        if sin < T::epsilon().sqrt() {
+            self.nlerp(&other, t)
+        } else {
+            let a = ((T::one() - t) * angle).sin() / sin;
+            let b = (t * angle).sin() / sin;
+
+            Quaternion::new(
+                self.w * a + other.w * b,
+                self.x * a + other.x * b,
+                self.y * a + other.y * b,
+                self.z * a + other.z * b,
+            )
+        }
+    }
+
+    /// the rotation by `angle` radians about `axis`, which need not be unit length, panics if
+    /// `axis` is not a non-zero 3-dimensional vector
+    #[inline]
+    pub fn from_axis_angle(axis: &Vector<T>, angle: T) -> Self {
+        assert!(axis.len() == 3, "from_axis_angle requires a 3-dimensional axis, found length {}", axis.len());
+        let k = match axis.normalize() {
+            Some(k) => k,
+            None => panic!("from_axis_angle requires a non-zero axis"),
+        };
+        let half = angle / (T::one() + T::one());
+        let s = half.sin();
+
+        Quaternion::new(half.cos(), k[0] * s, k[1] * s, k[2] * s)
+    }
+    /// the unit axis and angle in `[0, pi]` of the rotation, the identity returns the x axis
+    #[inline]
+    pub fn to_axis_angle(&self) -> (Vector<T>, T) {
+        let q = self.normalize().unwrap_or_else(Self::identity);
+        let q = if q.w < T::zero() { -q } else { q };
+        let axis = Vector::from([q.x, q.y, q.z]);
+
+        match axis.normalize() {
+            Some(k) => {
+                let angle = (T::one() + T::one()) * axis.norm_l2().atan2(q.w);
+                (k, angle)
+            },
+            None => (Vector::from([T::one(), T::zero(), T::zero()]), T::zero()),
+        }
+    }
+
+    /// the rotation by `roll` about x, then `pitch` about y, then `yaw` about z, in radians,
+    /// the aerospace (intrinsic z-y-x) convention
+    #[inline]
+    pub fn from_euler(roll: T, pitch: T, yaw: T) -> Self {
+        let two = T::one() + T::one();
+        let (sr, cr) = ((roll / two).sin(), (roll / two).cos());
+        let (sp, cp) = ((pitch / two).sin(), (pitch / two).cos());
+        let (sy, cy) = ((yaw / two).sin(), (yaw / two).cos());
+
+        Quaternion::new(
+            cr * cp * cy + sr * sp * sy,
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+        )
+    }
+    /// the `(roll, pitch, yaw)` of `from_euler`, with pitch in `[-pi/2, pi/2]` and roll and yaw
+    /// in `(-pi, pi]`, at gimbal lock only `yaw - roll` or `yaw + roll` is defined so roll is 0
+    ///
+    /// works from the half angle sums and differences, which stay well conditioned near the
+    /// poles where `asin` and the usual `atan2` forms lose precision
+    #[inline]
+    pub fn to_euler(&self) -> (T, T, T) {
+        let q = self;
+        let two = T::one() + T::one();
+
+        // with half angles, w + y and z - x are |a| times the cos and sin of (yaw - roll) / 2,
+        // w - y and z + x are |b| times the cos and sin of (yaw + roll) / 2
+        let a = (q.w + q.y).hypot(q.z - q.x);
+        let b = (q.w - q.y).hypot(q.z + q.x);
+        let pitch = two * a.atan2(b) - T::from_f64(PI / 2.0);
+
+        let diff = (q.z - q.x).atan2(q.w + q.y);
+        let sum = (q.z + q.x).atan2(q.w - q.y);
+
+        if b <= T::epsilon() * a {
+            (T::zero(), pitch, wrap_angle(two * diff))
+        } else if a <= T::epsilon() * b {
+            (T::zero(), pitch, wrap_angle(two * sum))
+        } else {
+            (wrap_angle(sum - diff), pitch, wrap_angle(sum + diff))
+        }
+    }
+
+    /// the 3x3 rotation matrix, `self` need not be unit length
+    #[inline]
+    pub fn to_matrix3(&self) -> Matrix<T> {
+        let m = self.to_matrix4();
+        Matrix::from_fn(3, 3, |i, j| m[i][j])
+    }
+    /// the 4x4 homogeneous rotation matrix, `self` need not be unit length
+    #[inline]
+    pub fn to_matrix4(&self) -> Matrix<T> {
+        let Quaternion { w, x, y, z } = *self;
+        let s = (T::one() + T::one()) / self.dot(self);
+        let (o, l) = (T::zero(), T::one());
+
+        Matrix::from([
+            [l - s * (y * y + z * z), s * (x * y - w * z), s * (x * z + w * y), o],
+            [s * (x * y + w * z), l - s * (x * x + z * z), s * (y * z - w * x), o],
+            [s * (x * z - w * y), s * (y * z + w * x), l - s * (x * x + y * y), o],
+            [o, o, o, l],
+        ])
+    }
+    /// the unit quaternion of a 3x3 rotation matrix or the rotation part of a 4x4 matrix
+    ///
+    /// uses Shepperd's method, solving for the largest component first, so it stays accurate
+    /// for rotations near 180 degrees
+    #[inline]
+    pub fn from_matrix(m: &Matrix<T>) -> Self {
+        assert!((m.rows() == 3 && m.cols() == 3) || (m.rows() == 4 && m.cols() == 4),
+            "from_matrix requires a 3x3 or 4x4 matrix, found {}x{}", m.rows(), m.cols());
+
+        let one = T::one();
+        let quarter = one / (T::one() + T::one() + T::one() + T::one());
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        let q = if trace >= m[0][0] && trace >= m[1][1] && trace >= m[2][2] {
+            let s = (one + trace).sqrt() * (one + one);
+            Quaternion::new(quarter * s, (m[2][1] - m[1][2]) / s, (m[0][2] - m[2][0]) / s, (m[1][0] - m[0][1]) / s)
+        } else if m[0][0] >= m[1][1] && m[0][0] >= m[2][2] {
+            let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * (one + one);
+            Quaternion::new((m[2][1] - m[1][2]) / s, quarter * s, (m[0][1] + m[1][0]) / s, (m[0][2] + m[2][0]) / s)
+        } else if m[1][1] >= m[2][2] {
+            let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt() * (one + one);
+            Quaternion::new((m[0][2] - m[2][0]) / s, (m[0][1] + m[1][0]) / s, quarter * s, (m[1][2] + m[2][1]) / s)
+        } else {
+            let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt() * (one + one);
+            Quaternion::new((m[1][0] - m[0][1]) / s, (m[0][2] + m[2][0]) / s, (m[1][2] + m[2][1]) / s, quarter * s)
+        };
+
+        q.normalize().unwrap_or(q)
+    }
+
+    /// rotates a 3-dimensional vector by the unit quaternion `self`
+    #[inline]
+    pub fn rotate(&self, v: &Vector<T>) -> Vector<T> {
+        assert!(v.len() == 3, "rotate requires a 3-dimensional vector, found length {}", v.len());
+
+        // v + 2w (u x v) + 2 u x (u x v)
+        let u = Vector::from([self.x, self.y, self.z]);
+        let two = T::one() + T::one();
+        let uv = u.cross(v);
+        let uuv = u.cross(&uv);
+
+        Vector::from_fn(3, |i| v[i] + two * (self.w * uv[i] + uuv[i]))
+    }
+}
+
+/// the Hamilton product, `a * b` rotates by `b` and then by `a`
+impl<T: Float> Mul for Quaternion<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, b: Self) -> Self::Output {
+        let a = self;
+
+        Quaternion::new(
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        )
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Quaternion<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+
+    fn assert_same_rotation(a: &Quaternion<f64>, b: &Quaternion<f64>, tolerance: f64) {
+        // q and -q are the same rotation
+        assert!(1.0 - a.dot(b).abs() < tolerance, "{:?} != {:?}", a, b);
+    }
+    fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>, tolerance: f64) {
+        assert!(a.iter().zip(b.iter()).all(|(x, y)| (x - y).abs() < tolerance), "{:?} != {:?}", a, b);
+    }
+
+
+    #[test]
+    fn test_hamilton_product() {
+        let i = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+        let j = Quaternion::new(0.0, 0.0, 1.0, 0.0);
+        let k = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, -k);
+        assert_eq!(i * i, Quaternion::new(-1.0, 0.0, 0.0, 0.0));
+        assert_eq!(i * j * k, Quaternion::new(-1.0, 0.0, 0.0, 0.0));
+    }
+    #[test]
+    fn test_inverse_normalize() {
+        let q: Quaternion<f64> = Quaternion::new(1.0, 2.0, -2.0, 4.0);
+        assert_eq!(q.norm(), 5.0);
+
+        let p = q * q.inverse().unwrap();
+        assert!((p.w - 1.0).abs() < 1e-15 && p.x.abs() < 1e-15 && p.y.abs() < 1e-15 && p.z.abs() < 1e-15);
+        assert!((q.normalize().unwrap().norm() - 1.0).abs() < 1e-15);
+        assert_eq!(Quaternion::new(0.0, 0.0, 0.0, 0.0).inverse(), None);
+    }
+    #[test]
+    fn test_axis_angle() {
+        let axis = Vector::from([1.0, 2.0, 3.0]);
+        let q: Quaternion<f64> = Quaternion::from_axis_angle(&axis, 1.25);
+        let (k, angle) = q.to_axis_angle();
+
+        assert!((angle - 1.25).abs() < 1e-15);
+        assert!(k.distance(&axis.normalize().unwrap()) < 1e-15);
+        assert_close(&q.to_matrix4(), &Matrix::rotation_axis_angle(&axis, 1.25), 1e-15);
+
+        let v = Vector::from([0.5, -1.0, 2.0]);
+        let rotated = Matrix::rotation_axis_angle(&axis, 1.25).transform_vector(&v);
+        assert!(q.rotate(&v).distance(&rotated) < 1e-14);
+
+        assert_eq!(Quaternion::<f64>::identity().to_axis_angle().1, 0.0);
+    }
+    #[test]
+    fn test_slerp_nlerp() {
+        let z = Vector::from([0.0, 0.0, 1.0]);
+        let a: Quaternion<f64> = Quaternion::identity();
+        let b = Quaternion::from_axis_angle(&z, 1.5);
+
+        assert_same_rotation(&a.slerp(&b, 0.5), &Quaternion::from_axis_angle(&z, 0.75), 1e-15);
+        assert_same_rotation(&a.slerp(&b, 1.0), &b, 1e-15);
+        assert_same_rotation(&a.slerp(&-b, 0.5), &Quaternion::from_axis_angle(&z, 0.75), 1e-15);
+        assert_same_rotation(&a.nlerp(&b, 0.5), &Quaternion::from_axis_angle(&z, 0.75), 1e-15);
+        assert_same_rotation(&b.slerp(&b, 0.3), &b, 1e-15);
+    }
+    #[test]
+    fn test_euler_round_trip() {
+        let angles = [-3.0, -1.5, -0.2, 0.0, 0.4, 1.2, 3.1];
+
+        for &roll in angles.iter() {
+            for &pitch in angles.iter().filter(|p| p.abs() < PI / 2.0) {
+                for &yaw in angles.iter() {
+                    let q: Quaternion<f64> = Quaternion::from_euler(roll, pitch, yaw);
+                    let (r, p, y) = q.to_euler();
+                    assert!((r - roll).abs() < 1e-12 && (p - pitch).abs() < 1e-12 && (y - yaw).abs() < 1e-12,
+                        "{:?} != {:?}", (r, p, y), (roll, pitch, yaw));
+                }
+            }
+        }
+
+        // yaw about z then the matrix rotates x to y
+        let m = Quaternion::<f64>::from_euler(0.0, 0.0, PI / 2.0).to_matrix3();
+        assert_close(&m, &Matrix::from([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]), 1e-15);
+    }
+    #[test]
+    fn test_euler_gimbal_lock() {
+        for &pitch in &[PI / 2.0, -PI / 2.0, PI / 2.0 - 1e-9, -PI / 2.0 + 1e-12, PI / 2.0 - 1e-6] {
+            for &(roll, yaw) in &[(0.3, 0.2), (-2.5, 1.0), (0.0, 3.0)] {
+                let q: Quaternion<f64> = Quaternion::from_euler(roll, pitch, yaw);
+                let (r, p, y) = q.to_euler();
+
+                assert!(r.is_finite() && p.is_finite() && y.is_finite());
+                assert!(p.abs() <= PI / 2.0);
+                assert_same_rotation(&Quaternion::from_euler(r, p, y), &q, 1e-15);
+                assert_close(&Quaternion::from_euler(r, p, y).to_matrix3(), &q.to_matrix3(), 1e-12);
+            }
+        }
+
+        let (roll, pitch, _) = Quaternion::<f64>::from_euler(0.3, PI / 2.0, 0.2).to_euler();
+        assert_eq!(roll, 0.0);
+        assert!((pitch - PI / 2.0).abs() < 1e-15);
+    }
+    #[test]
+    fn test_matrix_round_trip() {
+        let axes = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, -1.0, 2.0]];
+
+        for axis in axes.iter() {
+            for &angle in &[0.0, 0.5, -2.0, PI, PI - 1e-9] {
+                let q: Quaternion<f64> = Quaternion::from_axis_angle(&Vector::from(*axis), angle);
+
+                assert_same_rotation(&Quaternion::from_matrix(&q.to_matrix3()), &q, 1e-15);
+                assert_same_rotation(&Quaternion::from_matrix(&q.to_matrix4()), &q, 1e-15);
+                assert_close(&Quaternion::from_matrix(&q.to_matrix4()).to_matrix4(), &q.to_matrix4(), 1e-14);
+            }
+        }
+    }
+}
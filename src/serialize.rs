@@ -0,0 +1,218 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use alloc::vec::Vec;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use super::matrix::Matrix;
+use super::vector::Vector;
+
+
+/// serializes as a sequence of its elements
+impl<T: Serialize> Serialize for Vector<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vector<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<T>::deserialize(deserializer).map(|data| data.into_iter().collect())
+    }
+}
+
+
+struct Elements<'a, T: 'a>(&'a Matrix<T>);
+
+impl<'a, T: 'a + Serialize> Serialize for Elements<'a, T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter())
+    }
+}
+
+/// serializes as a struct of `rows`, `cols` and the elements in row-major order as `data`
+impl<T: Serialize> Serialize for Matrix<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Matrix", 3)?;
+        state.serialize_field("rows", &self.rows())?;
+        state.serialize_field("cols", &self.cols())?;
+        state.serialize_field("data", &Elements(self))?;
+        state.end()
+    }
+}
+
+const FIELDS: &[&str] = &["rows", "cols", "data"];
+
+enum Field {
+    Rows,
+    Cols,
+    Data,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl<'de> Visitor<'de> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("`rows`, `cols` or `data`")
+            }
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                match value {
+                    "rows" => Ok(Field::Rows),
+                    "cols" => Ok(Field::Cols),
+                    "data" => Ok(Field::Data),
+                    _ => Err(de::Error::unknown_field(value, FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct MatrixVisitor<T> {
+    element: PhantomData<T>,
+}
+
+#[inline]
+fn build<T, E: de::Error>(rows: usize, cols: usize, data: Vec<T>) -> Result<Matrix<T>, E> {
+    match rows.checked_mul(cols) {
+        Some(len) if len == data.len() => {
+            let mut iter = data.into_iter();
+            Matrix::try_from_fn(rows, cols, |_, _| iter.next().unwrap()).ok_or_else(|| {
+                de::Error::custom(alloc::format!("a {}x{} matrix does not fit in memory", rows, cols))
+            })
+        },
+        Some(len) => Err(de::Error::invalid_length(data.len(), &&*alloc::format!(
+            "{} elements for a {}x{} matrix", len, rows, cols))),
+        None => Err(de::Error::custom(alloc::format!("a {}x{} matrix is too large", rows, cols))),
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for MatrixVisitor<T> {
+    type Value = Matrix<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("struct Matrix")
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let rows = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let cols = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let data = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        build(rows, cols, data)
+    }
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut rows = None;
+        let mut cols = None;
+        let mut data = None;
+
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Rows => {
+                    if rows.is_some() {
+                        return Err(de::Error::duplicate_field("rows"));
+                    }
+                    rows = Some(map.next_value()?);
+                },
+                Field::Cols => {
+                    if cols.is_some() {
+                        return Err(de::Error::duplicate_field("cols"));
+                    }
+                    cols = Some(map.next_value()?);
+                },
+                Field::Data => {
+                    if data.is_some() {
+                        return Err(de::Error::duplicate_field("data"));
+                    }
+                    data = Some(map.next_value()?);
+                },
+            }
+        }
+
+        let rows = rows.ok_or_else(|| de::Error::missing_field("rows"))?;
+        let cols = cols.ok_or_else(|| de::Error::missing_field("cols"))?;
+        let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
+        build(rows, cols, data)
+    }
+}
+
+/// fails if `data` does not hold exactly `rows * cols` elements
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Matrix<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("Matrix", FIELDS, MatrixVisitor {
+            element: PhantomData,
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use bincode;
+    use serde_json;
+
+    use super::*;
+
+
+    #[test]
+    fn test_vector_json() {
+        let v: Vector<f32> = Vector::from([1.0, 2.5]);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[1.0,2.5]");
+        assert_eq!(serde_json::from_str::<Vector<f32>>(&json).unwrap(), v);
+    }
+    #[test]
+    fn test_matrix_json() {
+        let m: Matrix<f32> = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, r#"{"rows":2,"cols":3,"data":[1.0,2.0,3.0,4.0,5.0,6.0]}"#);
+        assert_eq!(serde_json::from_str::<Matrix<f32>>(&json).unwrap(), m);
+
+        let empty: Matrix<f32> = serde_json::from_str(r#"{"rows":0,"cols":4,"data":[]}"#).unwrap();
+        assert_eq!(empty, Matrix::zeroed(0, 4));
+    }
+    #[test]
+    fn test_matrix_json_bad_shape() {
+        let err = serde_json::from_str::<Matrix<f32>>(r#"{"rows":2,"cols":2,"data":[1.0,2.0,3.0]}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("4 elements for a 2x2 matrix"));
+
+        assert!(serde_json::from_str::<Matrix<f32>>(r#"{"rows":2,"data":[]}"#).is_err());
+        assert!(serde_json::from_str::<Matrix<f32>>(
+            r#"{"rows":18446744073709551615,"cols":2,"data":[]}"#).is_err());
+    }
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_matrix_json_huge_rows() {
+        // no elements, but the rows alone would overflow the row storage
+        let err = serde_json::from_str::<Matrix<f32>>(r#"{"rows":4611686018427387904,"cols":0,"data":[]}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("a 4611686018427387904x0 matrix does not fit in memory"));
+
+        let m: Matrix<f32> = serde_json::from_str(r#"{"rows":3,"cols":0,"data":[]}"#).unwrap();
+        assert_eq!(m, Matrix::zeroed(3, 0));
+    }
+    #[test]
+    fn test_matrix_bincode() {
+        let m: Matrix<i64> = Matrix::from_fn(3, 2, |i, j| (i * 2 + j) as i64 - 3);
+        let bytes = bincode::serialize(&m).unwrap();
+        assert_eq!(bincode::deserialize::<Matrix<i64>>(&bytes).unwrap(), m);
+
+        let v: Vector<u8> = Vector::from([1, 2, 3]);
+        let bytes = bincode::serialize(&v).unwrap();
+        assert_eq!(bincode::deserialize::<Vector<u8>>(&bytes).unwrap(), v);
+    }
+}
@@ -0,0 +1,238 @@
+use core::cmp;
+use core::ops::*;
+
+use zero::Zero;
+
+use super::matrix::Matrix;
+
+
+/// products whose smallest dimension is at or below this size use the standard kernel
+pub const STRASSEN_CUTOFF: usize = 128;
+
+
+impl<T> Matrix<T>
+    where T: Zero + Clone + AddAssign<T>,
+          for<'a, 'b> &'a T: Add<&'b T, Output = T> +
+                             Sub<&'b T, Output = T> +
+                             Mul<&'b T, Output = T>,
+{
+    /// multiplies `self` by `other` using Strassen–Winograd recursion above `STRASSEN_CUTOFF`
+    #[inline(always)]
+    pub fn mul_strassen(&self, other: &Matrix<T>) -> Matrix<T> {
+        self.mul_strassen_with_cutoff(other, STRASSEN_CUTOFF)
+    }
+    /// multiplies `self` by `other` using Strassen–Winograd recursion, falling back to the
+    /// standard kernel once any dimension of a sub-product is at or below `cutoff`
+    #[inline]
+    pub fn mul_strassen_with_cutoff(&self, other: &Matrix<T>, cutoff: usize) -> Matrix<T> {
+        assert!(self.cols() == other.rows(), "A * B, A's columns does not match B's rows");
+        strassen::<T>(self, other, cmp::max(cutoff, 1))
+    }
+}
+
+#[inline]
+fn strassen<T>(a: &Matrix<T>, b: &Matrix<T>, cutoff: usize) -> Matrix<T>
+    where T: Zero + Clone + AddAssign<T>,
+          for<'a, 'b> &'a T: Add<&'b T, Output = T> +
+                             Sub<&'b T, Output = T> +
+                             Mul<&'b T, Output = T>,
+{
+    let m = a.rows();
+    let k = a.cols();
+    let n = b.cols();
+
+    if m <= cutoff || k <= cutoff || n <= cutoff {
+        let mut out = Matrix::zeroed(m, n);
+        Matrix::<T>::mul(&mut out, a, b);
+        return out;
+    }
+
+    // odd dimensions are padded with zeros so every quadrant has the same shape
+    let hm = m.div_ceil(2);
+    let hk = k.div_ceil(2);
+    let hn = n.div_ceil(2);
+
+    let a11 = quadrant::<T>(a, 0, 0, hm, hk);
+    let a12 = quadrant::<T>(a, 0, hk, hm, hk);
+    let a21 = quadrant::<T>(a, hm, 0, hm, hk);
+    let a22 = quadrant::<T>(a, hm, hk, hm, hk);
+
+    let b11 = quadrant::<T>(b, 0, 0, hk, hn);
+    let b12 = quadrant::<T>(b, 0, hn, hk, hn);
+    let b21 = quadrant::<T>(b, hk, 0, hk, hn);
+    let b22 = quadrant::<T>(b, hk, hn, hk, hn);
+
+    let s1 = add::<T>(&a21, &a22);
+    let s2 = sub::<T>(&s1, &a11);
+    let s3 = sub::<T>(&a11, &a21);
+    let s4 = sub::<T>(&a12, &s2);
+
+    let t1 = sub::<T>(&b12, &b11);
+    let t2 = sub::<T>(&b22, &t1);
+    let t3 = sub::<T>(&b22, &b12);
+    let t4 = sub::<T>(&t2, &b21);
+
+    let p1 = strassen::<T>(&a11, &b11, cutoff);
+    let p2 = strassen::<T>(&a12, &b21, cutoff);
+    let p3 = strassen::<T>(&s4, &b22, cutoff);
+    let p4 = strassen::<T>(&a22, &t4, cutoff);
+    let p5 = strassen::<T>(&s1, &t1, cutoff);
+    let p6 = strassen::<T>(&s2, &t2, cutoff);
+    let p7 = strassen::<T>(&s3, &t3, cutoff);
+
+    let u2 = add::<T>(&p1, &p6);
+    let u3 = add::<T>(&u2, &p7);
+    let u4 = add::<T>(&u2, &p5);
+
+    let c11 = add::<T>(&p1, &p2);
+    let c12 = add::<T>(&u4, &p3);
+    let c21 = sub::<T>(&u3, &p4);
+    let c22 = add::<T>(&u3, &p5);
+
+    let mut out = Matrix::zeroed(m, n);
+    place::<T>(&mut out, &c11, 0, 0);
+    place::<T>(&mut out, &c12, 0, hn);
+    place::<T>(&mut out, &c21, hm, 0);
+    place::<T>(&mut out, &c22, hm, hn);
+    out
+}
+
+#[inline]
+fn quadrant<T>(m: &Matrix<T>, row: usize, col: usize, rows: usize, cols: usize) -> Matrix<T>
+    where T: Zero + Clone,
+{
+    let mut out = Matrix::zeroed(rows, cols);
+
+    for i in 0..rows {
+        for j in 0..cols {
+            out[i][j] = if row + i < m.rows() && col + j < m.cols() {
+                m[row + i][col + j].clone()
+            } else {
+                T::zero()
+            };
+        }
+    }
+    out
+}
+
+#[inline]
+fn place<T>(out: &mut Matrix<T>, m: &Matrix<T>, row: usize, col: usize)
+    where T: Clone,
+{
+    let rows = cmp::min(m.rows(), out.rows() - row);
+    let cols = cmp::min(m.cols(), out.cols() - col);
+
+    for i in 0..rows {
+        for j in 0..cols {
+            out[row + i][col + j] = m[i][j].clone();
+        }
+    }
+}
+
+#[inline]
+fn add<T>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T>
+    where T: Zero,
+          for<'a, 'b> &'a T: Add<&'b T, Output = T>,
+{
+    let mut out = Matrix::zeroed(a.rows(), a.cols());
+
+    for i in 0..a.rows() {
+        for j in 0..a.cols() {
+            out[i][j] = &a[i][j] + &b[i][j];
+        }
+    }
+    out
+}
+
+#[inline]
+fn sub<T>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T>
+    where T: Zero,
+          for<'a, 'b> &'a T: Sub<&'b T, Output = T>,
+{
+    let mut out = Matrix::zeroed(a.rows(), a.cols());
+
+    for i in 0..a.rows() {
+        for j in 0..a.cols() {
+            out[i][j] = &a[i][j] - &b[i][j];
+        }
+    }
+    out
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+
+    #[test]
+    fn test_strassen_integer_exact() {
+        let mut seed = 1;
+        let a: Matrix<i64> = random(&mut seed, 37, 53, |x| (x % 201) as i64 - 100);
+        let b: Matrix<i64> = random(&mut seed, 53, 29, |x| (x % 201) as i64 - 100);
+
+        assert_eq!(a.mul_strassen_with_cutoff(&b, 4), &a * &b);
+        assert_eq!(a.mul_strassen_with_cutoff(&b, 1), &a * &b);
+    }
+    #[test]
+    fn test_strassen_below_cutoff() {
+        let a: Matrix<usize> = Matrix::identity(4, 4);
+        let b: Matrix<usize> = Matrix::identity(4, 4);
+        assert_eq!(a.mul_strassen(&b), Matrix::identity(4, 4));
+    }
+    #[test]
+    fn test_strassen_f64_error_bound() {
+        let n = 96;
+        let mut seed = 7;
+        let a: Matrix<f64> = random(&mut seed, n, n, unit_f64);
+        let b: Matrix<f64> = random(&mut seed, n, n, unit_f64);
+
+        let c = a.mul_strassen_with_cutoff(&b, 8);
+        let d = &a * &b;
+        // entries are in [-1, 1), so |C| <= n and the Winograd error is a small multiple of n eps
+        let bound = (n * n) as f64 * 2.220446049250313e-16;
+
+        for i in 0..n {
+            for j in 0..n {
+                let diff = c[i][j] - d[i][j];
+                assert!((if diff < 0.0 { -diff } else { diff }) <= bound);
+            }
+        }
+    }
+    #[test]
+    fn test_strassen_f32_error_bound() {
+        let n = 64;
+        let mut seed = 11;
+        let a: Matrix<f32> = random(&mut seed, n, n, |x| unit_f64(x) as f32);
+        let b: Matrix<f32> = random(&mut seed, n, n, |x| unit_f64(x) as f32);
+
+        let c = a.mul_strassen_with_cutoff(&b, 4);
+        let d = &a * &b;
+        let bound = (n * n) as f32 * 1.1920929e-7;
+
+        for i in 0..n {
+            for j in 0..n {
+                let diff = c[i][j] - d[i][j];
+                assert!((if diff < 0.0 { -diff } else { diff }) <= bound);
+            }
+        }
+    }
+
+
+    fn unit_f64(x: u64) -> f64 {
+        (x % 2000) as f64 / 1000.0 - 1.0
+    }
+    fn random<T, F>(seed: &mut u64, rows: usize, cols: usize, f: F) -> Matrix<T>
+        where T: Zero,
+              F: Fn(u64) -> T,
+    {
+        let mut m = Matrix::zeroed(rows, cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                m[i][j] = f(*seed >> 33);
+            }
+        }
+        m
+    }
+}
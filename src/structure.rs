@@ -0,0 +1,250 @@
+use core::cmp;
+use core::ops::*;
+
+use zero::Zero;
+
+use super::float::Float;
+use super::matrix::Matrix;
+use super::vector::Vector;
+
+
+/// the tolerance for treating `m` as symmetric when rounding error is all that separates
+/// `m[i][j]` from `m[j][i]`
+#[inline]
+pub(crate) fn rounding_tolerance<T: Float>(m: &Matrix<T>) -> T {
+    m.norm_max() * T::epsilon() * T::from_f64(m.rows() as f64)
+}
+
+
+impl<T> Matrix<T>
+    where T: Zero + Clone + AddAssign<T>,
+{
+    /// the sum of the main diagonal, panics if the matrix is not square
+    #[inline]
+    pub fn trace(&self) -> T {
+        assert!(self.rows() == self.cols(), "can not find the trace of a {}x{} matrix", self.rows(), self.cols());
+
+        let mut sum = T::zero();
+        for i in 0..self.rows() {
+            sum += self[i][i].clone();
+        }
+        sum
+    }
+}
+
+impl<T> Matrix<T>
+    where T: Clone,
+{
+    /// the main diagonal, of length `min(rows, cols)`
+    #[inline]
+    pub fn diagonal(&self) -> Vector<T> {
+        Vector::from_fn(cmp::min(self.rows(), self.cols()), |i| self[i][i].clone())
+    }
+}
+
+impl<T> Matrix<T>
+    where T: Zero + Clone,
+{
+    /// copies the elements on and above diagonal `k` and zeros the rest, `k = 0` is the main
+    /// diagonal, positive `k` above it and negative `k` below it
+    #[inline]
+    pub fn upper_triangle(&self, k: isize) -> Matrix<T> {
+        Matrix::from_fn(self.rows(), self.cols(), |i, j| {
+            if j as isize - i as isize >= k { self[i][j].clone() } else { T::zero() }
+        })
+    }
+    /// copies the elements on and below diagonal `k` and zeros the rest, `k = 0` is the main
+    /// diagonal, positive `k` above it and negative `k` below it
+    #[inline]
+    pub fn lower_triangle(&self, k: isize) -> Matrix<T> {
+        Matrix::from_fn(self.rows(), self.cols(), |i, j| {
+            if j as isize - i as isize <= k { self[i][j].clone() } else { T::zero() }
+        })
+    }
+}
+
+impl<T> Matrix<T>
+    where T: Zero + PartialEq,
+{
+    /// the lower and upper bandwidths, how far the furthest non-zero elements lie below and
+    /// above the main diagonal, `(0, 0)` for a diagonal matrix
+    #[inline]
+    pub fn bandwidth(&self) -> (usize, usize) {
+        let zero = T::zero();
+        let (mut lower, mut upper) = (0, 0);
+
+        for (i, row) in self.row_iter().enumerate() {
+            for (j, x) in row.iter().enumerate() {
+                if *x != zero {
+                    if i > j {
+                        lower = cmp::max(lower, i - j);
+                    } else {
+                        upper = cmp::max(upper, j - i);
+                    }
+                }
+            }
+        }
+        (lower, upper)
+    }
+
+    /// returns true if every element off the main diagonal is zero
+    #[inline]
+    pub fn is_diagonal(&self) -> bool {
+        self.bandwidth() == (0, 0)
+    }
+    /// returns true if every element below the main diagonal is zero
+    #[inline]
+    pub fn is_upper_triangular(&self) -> bool {
+        self.bandwidth().0 == 0
+    }
+    /// returns true if every element above the main diagonal is zero
+    #[inline]
+    pub fn is_lower_triangular(&self) -> bool {
+        self.bandwidth().1 == 0
+    }
+    /// returns true if the matrix is upper or lower triangular
+    #[inline]
+    pub fn is_triangular(&self) -> bool {
+        let (lower, upper) = self.bandwidth();
+        lower == 0 || upper == 0
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// returns true if the matrix is square and `|m[i][j] - m[j][i]| <= tolerance` everywhere
+    #[inline]
+    pub fn is_symmetric(&self, tolerance: T) -> bool {
+        self.rows() == self.cols() &&
+            (0..self.rows()).all(|i| (0..i).all(|j| (self[i][j] - self[j][i]).abs() <= tolerance))
+    }
+    /// returns true if the matrix is square and `m^T m` is within `tolerance` of the identity
+    /// in every element
+    #[inline]
+    pub fn is_orthogonal(&self, tolerance: T) -> bool {
+        let n = self.rows();
+        if n != self.cols() {
+            return false;
+        }
+
+        (0..n).all(|i| (i..n).all(|j| {
+            let dot = (0..n).fold(T::zero(), |sum, k| sum + self[k][i] * self[k][j]);
+            let expected = if i == j { T::one() } else { T::zero() };
+            (dot - expected).abs() <= tolerance
+        }))
+    }
+    /// returns true if the matrix is symmetric to within rounding error and has a Cholesky
+    /// factorization, i.e. `x^T m x > 0` for every non-zero `x`
+    pub fn is_positive_definite(&self) -> bool {
+        let n = self.rows();
+        if !self.is_symmetric(rounding_tolerance(self)) {
+            return false;
+        }
+
+        // the lower Cholesky factor, row by row, fails on a pivot that is not positive
+        let mut l: Matrix<T> = Matrix::zeroed(n, n);
+        for i in 0..n {
+            for j in 0..i + 1 {
+                let sum = (0..j).fold(self[i][j], |sum, k| sum - l[i][k] * l[j][k]);
+
+                if i == j {
+                    if sum <= T::zero() || !sum.is_finite() {
+                        return false;
+                    }
+                    l[i][i] = sum.sqrt();
+                } else {
+                    l[i][j] = sum / l[j][j];
+                }
+            }
+        }
+        true
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+
+    #[test]
+    fn test_trace_diagonal() {
+        let m: Matrix<i32> = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(m.trace(), 15);
+        assert_eq!(&*m.diagonal(), &[1, 5, 9]);
+        assert_eq!(&*Matrix::<i32>::from([[1, 2, 3], [4, 5, 6]]).diagonal(), &[1, 5]);
+        assert_eq!(Matrix::<i32>::zeroed(0, 0).trace(), 0);
+    }
+    #[test]
+    #[should_panic(expected = "can not find the trace of a 2x3 matrix")]
+    fn test_trace_not_square() {
+        Matrix::<i32>::zeroed(2, 3).trace();
+    }
+    #[test]
+    fn test_triangles() {
+        let m: Matrix<i32> = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(m.upper_triangle(0), Matrix::from([[1, 2, 3], [0, 5, 6], [0, 0, 9]]));
+        assert_eq!(m.upper_triangle(1), Matrix::from([[0, 2, 3], [0, 0, 6], [0, 0, 0]]));
+        assert_eq!(m.upper_triangle(-1), Matrix::from([[1, 2, 3], [4, 5, 6], [0, 8, 9]]));
+        assert_eq!(m.lower_triangle(0), Matrix::from([[1, 0, 0], [4, 5, 0], [7, 8, 9]]));
+        assert_eq!(m.lower_triangle(-2), Matrix::from([[0, 0, 0], [0, 0, 0], [7, 0, 0]]));
+        assert_eq!(m.lower_triangle(5), m);
+
+        let wide: Matrix<i32> = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(wide.upper_triangle(1), Matrix::from([[0, 2, 3], [0, 0, 6]]));
+    }
+    #[test]
+    fn test_bandwidth() {
+        let tridiagonal: Matrix<i32> = Matrix::from([[2, -1, 0, 0], [-1, 2, -1, 0], [0, -1, 2, -1], [0, 0, -1, 2]]);
+        assert_eq!(tridiagonal.bandwidth(), (1, 1));
+        assert!(!tridiagonal.is_triangular());
+
+        let m: Matrix<i32> = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(m.upper_triangle(1).bandwidth(), (0, 2));
+        assert_eq!(m.lower_triangle(-1).bandwidth(), (2, 0));
+        assert_eq!(Matrix::<i32>::zeroed(3, 4).bandwidth(), (0, 0));
+    }
+    #[test]
+    fn test_is_diagonal_triangular() {
+        let m: Matrix<i32> = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert!(Matrix::<i32>::identity(3, 3).is_diagonal());
+        assert!(Matrix::<i32>::from([[1, 0, 0], [0, 2, 0]]).is_diagonal());
+        assert!(!m.is_diagonal());
+
+        assert!(m.upper_triangle(0).is_upper_triangular());
+        assert!(!m.upper_triangle(0).is_lower_triangular());
+        assert!(m.lower_triangle(0).is_lower_triangular());
+        assert!(m.lower_triangle(0).is_triangular());
+        assert!(!m.is_triangular());
+    }
+    #[test]
+    fn test_is_symmetric() {
+        let m: Matrix<f64> = Matrix::from([[1.0, 2.0], [2.0 + 1e-12, 3.0]]);
+        assert!(m.is_symmetric(1e-9));
+        assert!(!m.is_symmetric(0.0));
+        assert!(!Matrix::<f64>::zeroed(2, 3).is_symmetric(1.0));
+    }
+    #[test]
+    fn test_is_orthogonal() {
+        let angle = 0.3f64;
+        let rotation: Matrix<f64> = Matrix::from([[angle.cos(), -angle.sin()], [angle.sin(), angle.cos()]]);
+        assert!(rotation.is_orthogonal(1e-15));
+        assert!(Matrix::<f64>::from([[0.0, 1.0], [1.0, 0.0]]).is_orthogonal(0.0));
+        assert!(!Matrix::<f64>::from([[2.0, 0.0], [0.0, 0.5]]).is_orthogonal(1e-9));
+        assert!(!Matrix::<f64>::zeroed(2, 3).is_orthogonal(1.0));
+    }
+    #[test]
+    fn test_is_positive_definite() {
+        let tridiagonal: Matrix<f64> = Matrix::from([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+        assert!(tridiagonal.is_positive_definite());
+        assert!(Matrix::<f32>::identity(4, 4).is_positive_definite());
+        assert!(Matrix::<f64>::zeroed(0, 0).is_positive_definite());
+
+        // symmetric with eigenvalues 3 and -1
+        assert!(!Matrix::<f64>::from([[1.0, 2.0], [2.0, 1.0]]).is_positive_definite());
+        // positive semi-definite but singular
+        assert!(!Matrix::<f64>::from([[1.0, 1.0], [1.0, 1.0]]).is_positive_definite());
+        // positive eigenvalues but not symmetric
+        assert!(!Matrix::<f64>::from([[1.0, 5.0], [0.0, 1.0]]).is_positive_definite());
+        assert!(!Matrix::<f64>::from([[f64::NAN]]).is_positive_definite());
+    }
+}
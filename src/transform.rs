@@ -0,0 +1,295 @@
+use super::float::Float;
+use super::matrix::Matrix;
+use super::vector::Vector;
+
+
+/// affine and projective transforms on homogeneous coordinates, matrices act on column vectors
+/// (`m * v`) with the translation in the last column, projections follow the OpenGL convention
+/// of a right-handed view space looking down `-z` and clip space depth from -1 to 1
+impl<T: Float> Matrix<T> {
+    /// translates `n`-dimensional points by `offset`, an `(n + 1) x (n + 1)` matrix
+    #[inline]
+    pub fn translation(offset: &Vector<T>) -> Matrix<T> {
+        let n = offset.len();
+        Matrix::from_fn(n + 1, n + 1, |i, j| {
+            if i == j {
+                T::one()
+            } else if j == n && i < n {
+                offset[i]
+            } else {
+                T::zero()
+            }
+        })
+    }
+    /// scales each axis of `n`-dimensional points by `factors`, an `(n + 1) x (n + 1)` matrix
+    #[inline]
+    pub fn scaling(factors: &Vector<T>) -> Matrix<T> {
+        let n = factors.len();
+        Matrix::from_fn(n + 1, n + 1, |i, j| {
+            if i != j {
+                T::zero()
+            } else if i < n {
+                factors[i]
+            } else {
+                T::one()
+            }
+        })
+    }
+
+    /// rotates 2D points counter-clockwise by `angle` radians, a 3x3 matrix
+    #[inline]
+    pub fn rotation(angle: T) -> Matrix<T> {
+        let (s, c, o, l) = (angle.sin(), angle.cos(), T::zero(), T::one());
+        Matrix::from([
+            [c, -s, o],
+            [s, c, o],
+            [o, o, l],
+        ])
+    }
+    /// rotates 3D points by `angle` radians about the x axis, counter-clockwise looking down
+    /// the axis towards the origin, a 4x4 matrix
+    #[inline]
+    pub fn rotation_x(angle: T) -> Matrix<T> {
+        let (s, c, o, l) = (angle.sin(), angle.cos(), T::zero(), T::one());
+        Matrix::from([
+            [l, o, o, o],
+            [o, c, -s, o],
+            [o, s, c, o],
+            [o, o, o, l],
+        ])
+    }
+    /// rotates 3D points by `angle` radians about the y axis, a 4x4 matrix
+    #[inline]
+    pub fn rotation_y(angle: T) -> Matrix<T> {
+        let (s, c, o, l) = (angle.sin(), angle.cos(), T::zero(), T::one());
+        Matrix::from([
+            [c, o, s, o],
+            [o, l, o, o],
+            [-s, o, c, o],
+            [o, o, o, l],
+        ])
+    }
+    /// rotates 3D points by `angle` radians about the z axis, a 4x4 matrix
+    #[inline]
+    pub fn rotation_z(angle: T) -> Matrix<T> {
+        let (s, c, o, l) = (angle.sin(), angle.cos(), T::zero(), T::one());
+        Matrix::from([
+            [c, -s, o, o],
+            [s, c, o, o],
+            [o, o, l, o],
+            [o, o, o, l],
+        ])
+    }
+    /// rotates 3D points by `angle` radians about `axis`, which need not be unit length, a 4x4
+    /// matrix, panics if `axis` is not a non-zero 3-dimensional vector
+    #[inline]
+    pub fn rotation_axis_angle(axis: &Vector<T>, angle: T) -> Matrix<T> {
+        assert!(axis.len() == 3, "rotation_axis_angle requires a 3-dimensional axis, found length {}", axis.len());
+        let k = match axis.normalize() {
+            Some(k) => k,
+            None => panic!("rotation_axis_angle requires a non-zero axis"),
+        };
+        let (s, c) = (angle.sin(), angle.cos());
+        let t = T::one() - c;
+
+        // Rodrigues' formula, c I + s [k]x + (1 - c) k k^T
+        Matrix::from([
+            [c + t * k[0] * k[0], t * k[0] * k[1] - s * k[2], t * k[0] * k[2] + s * k[1], T::zero()],
+            [t * k[1] * k[0] + s * k[2], c + t * k[1] * k[1], t * k[1] * k[2] - s * k[0], T::zero()],
+            [t * k[2] * k[0] - s * k[1], t * k[2] * k[1] + s * k[0], c + t * k[2] * k[2], T::zero()],
+            [T::zero(), T::zero(), T::zero(), T::one()],
+        ])
+    }
+
+    /// a right-handed view matrix placing `eye` at the origin looking down `-z` towards `target`
+    #[inline]
+    pub fn look_at_rh(eye: &Vector<T>, target: &Vector<T>, up: &Vector<T>) -> Matrix<T> {
+        let (s, u, f) = look_at_basis(eye, target, up, true);
+        view(eye, &s, &u, &f.map(|x| -*x))
+    }
+    /// a left-handed view matrix placing `eye` at the origin looking down `+z` towards `target`
+    #[inline]
+    pub fn look_at_lh(eye: &Vector<T>, target: &Vector<T>, up: &Vector<T>) -> Matrix<T> {
+        let (s, u, f) = look_at_basis(eye, target, up, false);
+        view(eye, &s, &u, &f)
+    }
+
+    /// a perspective projection with a vertical field of view of `fovy` radians and
+    /// `aspect = width / height`, mapping depths `near..far` to -1..1
+    #[inline]
+    pub fn perspective(fovy: T, aspect: T, near: T, far: T) -> Matrix<T> {
+        let two = T::one() + T::one();
+        let f = T::one() / (fovy / two).tan();
+        let o = T::zero();
+
+        Matrix::from([
+            [f / aspect, o, o, o],
+            [o, f, o, o],
+            [o, o, (far + near) / (near - far), two * far * near / (near - far)],
+            [o, o, -T::one(), o],
+        ])
+    }
+    /// an orthographic projection mapping the box `left..right`, `bottom..top`, `-near..-far`
+    /// to -1..1 on every axis
+    #[inline]
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Matrix<T> {
+        let two = T::one() + T::one();
+        let o = T::zero();
+
+        Matrix::from([
+            [two / (right - left), o, o, -(right + left) / (right - left)],
+            [o, two / (top - bottom), o, -(top + bottom) / (top - bottom)],
+            [o, o, -two / (far - near), -(far + near) / (far - near)],
+            [o, o, o, T::one()],
+        ])
+    }
+
+    /// transforms an `n`-dimensional point by an `(n + 1) x (n + 1)` matrix, treating it as
+    /// homogeneous with `w = 1` and dividing the result by its `w`
+    #[inline]
+    pub fn transform_point(&self, point: &Vector<T>) -> Vector<T> {
+        let n = self.homogeneous_dim("transform_point", point);
+        let w = self.homogeneous_row(n, point, T::one());
+        Vector::from_fn(n, |i| self.homogeneous_row(i, point, T::one()) / w)
+    }
+    /// transforms an `n`-dimensional direction by an `(n + 1) x (n + 1)` matrix, treating it as
+    /// homogeneous with `w = 0` so translation does not apply
+    #[inline]
+    pub fn transform_vector(&self, vector: &Vector<T>) -> Vector<T> {
+        let n = self.homogeneous_dim("transform_vector", vector);
+        Vector::from_fn(n, |i| self.homogeneous_row(i, vector, T::zero()))
+    }
+
+    #[inline]
+    fn homogeneous_dim(&self, op: &str, v: &Vector<T>) -> usize {
+        assert!(self.rows() == self.cols() && self.cols() == v.len() + 1,
+            "{} requires a {}x{} matrix for a vector of length {}, found {}x{}",
+            op, v.len() + 1, v.len() + 1, v.len(), self.rows(), self.cols());
+        v.len()
+    }
+    /// row `i` of the matrix times `v` extended with `w`
+    #[inline]
+    fn homogeneous_row(&self, i: usize, v: &Vector<T>, w: T) -> T {
+        let row = &self[i];
+        v.iter().zip(row.iter()).fold(row[v.len()] * w, |sum, (x, m)| sum + *m * *x)
+    }
+}
+
+/// the side, up and forward unit vectors of a camera at `eye` looking at `target`
+#[inline]
+fn look_at_basis<T: Float>(eye: &Vector<T>, target: &Vector<T>, up: &Vector<T>, rh: bool)
+    -> (Vector<T>, Vector<T>, Vector<T>)
+{
+    assert!(eye.len() == 3 && target.len() == 3 && up.len() == 3, "look_at requires 3-dimensional vectors");
+
+    let f = target.zip_map(eye, |t, e| *t - *e).normalize();
+    let f = match f {
+        Some(f) => f,
+        None => panic!("look_at requires eye and target to differ"),
+    };
+    let s = if rh { f.cross(up) } else { up.cross(&f) };
+    let s = match s.normalize() {
+        Some(s) => s,
+        None => panic!("look_at requires up not to be parallel to the view direction"),
+    };
+    let u = if rh { s.cross(&f) } else { f.cross(&s) };
+
+    (s, u, f)
+}
+
+/// the view matrix with rows `s`, `u` and `z` translated so `eye` is the origin
+#[inline]
+fn view<T: Float>(eye: &Vector<T>, s: &Vector<T>, u: &Vector<T>, z: &Vector<T>) -> Matrix<T> {
+    let dot = |a: &Vector<T>| a.iter().zip(eye.iter()).fold(T::zero(), |sum, (x, e)| sum + *x * *e);
+    let o = T::zero();
+
+    Matrix::from([
+        [s[0], s[1], s[2], -dot(s)],
+        [u[0], u[1], u[2], -dot(u)],
+        [z[0], z[1], z[2], -dot(z)],
+        [o, o, o, T::one()],
+    ])
+}
+
+
+#[cfg(test)]
+mod test {
+    use core::f64::consts::PI;
+
+    use super::*;
+
+
+    fn assert_close(a: &Vector<f64>, b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x - y).abs() < 1e-12, "{:?} != {:?}", a, b);
+        }
+    }
+
+
+    #[test]
+    fn test_translation_scaling() {
+        let t: Matrix<f64> = Matrix::translation(&Vector::from([1.0, 2.0, 3.0]));
+        assert_eq!(t.rows(), 4);
+        assert_close(&t.transform_point(&Vector::from([1.0, 1.0, 1.0])), &[2.0, 3.0, 4.0]);
+        assert_close(&t.transform_vector(&Vector::from([1.0, 1.0, 1.0])), &[1.0, 1.0, 1.0]);
+
+        let s: Matrix<f64> = Matrix::scaling(&Vector::from([2.0, -1.0]));
+        assert_eq!(s.rows(), 3);
+        assert_close(&s.transform_point(&Vector::from([1.0, 1.0])), &[2.0, -1.0]);
+    }
+    #[test]
+    fn test_rotations() {
+        let x = Vector::from([1.0, 0.0, 0.0]);
+        let y = Vector::from([0.0, 1.0, 0.0]);
+        let z = Vector::from([0.0, 0.0, 1.0]);
+
+        assert_close(&Matrix::rotation_z(PI / 2.0).transform_point(&x), &[0.0, 1.0, 0.0]);
+        assert_close(&Matrix::rotation_x(PI / 2.0).transform_point(&y), &[0.0, 0.0, 1.0]);
+        assert_close(&Matrix::rotation_y(PI / 2.0).transform_point(&z), &[1.0, 0.0, 0.0]);
+        assert_close(&Matrix::rotation(PI / 2.0).transform_point(&Vector::from([1.0, 0.0])), &[0.0, 1.0]);
+
+        let axis = Matrix::rotation_axis_angle(&Vector::from([0.0, 0.0, 2.0]), 0.3);
+        let rz = Matrix::rotation_z(0.3);
+        assert!(axis.iter().zip(rz.iter()).all(|(a, b)| (a - b).abs() < 1e-15));
+
+        let diagonal = Matrix::rotation_axis_angle(&Vector::from([1.0, 1.0, 1.0]), 2.0 * PI / 3.0);
+        assert_close(&diagonal.transform_point(&x), &[0.0, 1.0, 0.0]);
+    }
+    #[test]
+    fn test_look_at() {
+        let eye = Vector::from([1.0, 2.0, 3.0]);
+        let target = Vector::from([1.0, 2.0, -7.0]);
+        let up = Vector::from([0.0, 1.0, 0.0]);
+
+        let rh = Matrix::look_at_rh(&eye, &target, &up);
+        assert_close(&rh.transform_point(&eye), &[0.0, 0.0, 0.0]);
+        assert_close(&rh.transform_point(&target), &[0.0, 0.0, -10.0]);
+        assert_close(&rh.transform_point(&Vector::from([2.0, 2.0, 3.0])), &[1.0, 0.0, 0.0]);
+
+        let lh = Matrix::look_at_lh(&eye, &target, &up);
+        assert_close(&lh.transform_point(&target), &[0.0, 0.0, 10.0]);
+        assert_close(&lh.transform_point(&Vector::from([1.0, 3.0, 3.0])), &[0.0, 1.0, 0.0]);
+    }
+    #[test]
+    #[should_panic]
+    fn test_look_at_parallel_up() {
+        let v = Vector::from([0.0, 1.0, 0.0]);
+        Matrix::look_at_rh(&Vector::from([0.0, 0.0, 0.0]), &v, &v);
+    }
+    #[test]
+    fn test_projections() {
+        let p: Matrix<f64> = Matrix::perspective(PI / 2.0, 2.0, 1.0, 10.0);
+        assert_close(&p.transform_point(&Vector::from([2.0, 1.0, -1.0])), &[1.0, 1.0, -1.0]);
+        assert_close(&p.transform_point(&Vector::from([0.0, 0.0, -10.0])), &[0.0, 0.0, 1.0]);
+
+        let o: Matrix<f64> = Matrix::orthographic(-2.0, 2.0, -1.0, 1.0, 1.0, 10.0);
+        assert_close(&o.transform_point(&Vector::from([-2.0, 1.0, -1.0])), &[-1.0, 1.0, -1.0]);
+        assert_close(&o.transform_point(&Vector::from([2.0, -1.0, -10.0])), &[1.0, -1.0, 1.0]);
+    }
+    #[test]
+    #[should_panic]
+    fn test_transform_point_wrong_len() {
+        Matrix::<f64>::identity(4, 4).transform_point(&Vector::from([1.0, 2.0]));
+    }
+}
@@ -1,7 +1,11 @@
 use core::fmt;
+use core::iter::FromIterator;
 use core::ops::*;
+use core::slice;
 
-use array::Array;
+use alloc::vec::{self, Vec};
+
+use one::One;
 use zero::Zero;
 
 use super::matrix::Matrix;
@@ -9,56 +13,202 @@ use super::matrix::Matrix;
 
 #[derive(Clone)]
 pub struct Vector<T> {
-    data: Array<T>,
+    data: Vec<T>,
 }
 
-unsafe impl<T: Send> Send for Vector<T> {}
-unsafe impl<T: Sync> Sync for Vector<T> {}
-
 impl<T: Default> Vector<T> {
-    #[inline(always)]
+    #[inline]
     pub fn new(len: usize) -> Self {
-        assert!(len != 0);
+        Vector::from_fn(len, |_| T::default())
+    }
+}
+
+impl<T> Vector<T> {
+    /// builds a vector of length `len` whose element at `i` is `f(i)`
+    #[inline]
+    pub fn from_fn<F>(len: usize, f: F) -> Self
+        where F: FnMut(usize) -> T,
+    {
         Vector {
-            data: Array::new(len),
+            data: (0..len).map(f).collect(),
         }
     }
 }
 
-impl<T> Vector<T> {
-    #[inline(always)]
-    pub fn zeroed(len: usize) -> Self {
-        assert!(len != 0);
+impl<T> Vector<T>
+    where T: Clone,
+{
+    #[inline]
+    pub fn from_slice(data: &[T]) -> Self {
         Vector {
-            data: Array::zeroed(len),
+            data: data.to_vec(),
         }
     }
+}
+
+impl<T> Vector<T> {
+    /// consumes the vector, laying its elements out in row-major order as a `rows` x `cols`
+    /// matrix
+    #[inline]
+    pub fn into_matrix(self, rows: usize, cols: usize) -> Matrix<T> {
+        assert!(rows.checked_mul(cols) == Some(self.len()),
+            "a {}x{} matrix can not hold {} elements", rows, cols, self.len());
+
+        let mut iter = self.data.into_iter();
+        Matrix::from_fn(rows, cols, |_, _| iter.next().unwrap())
+    }
+}
+
+impl<T: Zero> Vector<T> {
+    /// builds a vector of length `len` filled with `T::zero()`
+    #[inline]
+    pub fn zeroed(len: usize) -> Self {
+        Vector::from_fn(len, |_| T::zero())
+    }
 
     #[inline]
     pub fn of_vectors(rows: usize, cols: usize) -> Vector<Vector<T>> {
-        let mut data = Vector::zeroed(rows);
+        Vector::from_fn(rows, |_| Vector::zeroed(cols))
+    }
+}
 
-        for row in data.iter_mut() {
-            *row = Vector::zeroed(cols);
-        }
+impl<T> Vector<T> {
+    /// returns the element at `i`, or `None` if `i` is out of range
+    #[inline(always)]
+    pub fn get(&self, i: usize) -> Option<&T> {
+        self.data.get(i)
+    }
+    /// returns the element at `i` mutably, or `None` if `i` is out of range
+    #[inline(always)]
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        self.data.get_mut(i)
+    }
 
-        data
+    /// returns the element at `i` without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// `i` must be less than `self.len()`
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, i: usize) -> &T {
+        self.data.get_unchecked(i)
+    }
+    /// returns the element at `i` mutably without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// `i` must be less than `self.len()`
+    #[inline(always)]
+    pub unsafe fn get_unchecked_mut(&mut self, i: usize) -> &mut T {
+        self.data.get_unchecked_mut(i)
     }
+}
 
+impl<T> Vector<T> {
+    /// builds an empty vector with room for `capacity` elements
     #[inline(always)]
-    pub fn get(&self, i: usize) -> &T {
-        unsafe {
-            self.data.get_unchecked(i)
+    pub fn with_capacity(capacity: usize) -> Self {
+        Vector {
+            data: Vec::with_capacity(capacity),
         }
     }
     #[inline(always)]
-    pub fn get_mut(&mut self, i: usize) -> &mut T {
-        unsafe {
-            self.data.get_unchecked_mut(i)
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+    /// reserves capacity for at least `additional` more elements
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional)
+    }
+
+    #[inline(always)]
+    pub fn push(&mut self, value: T) {
+        self.data.push(value)
+    }
+    #[inline(always)]
+    pub fn pop(&mut self) -> Option<T> {
+        self.data.pop()
+    }
+    /// inserts `value` at `i`, shifting all elements after it, panics if `i > len`
+    #[inline(always)]
+    pub fn insert(&mut self, i: usize, value: T) {
+        self.data.insert(i, value)
+    }
+    /// removes and returns the element at `i`, shifting all elements after it
+    #[inline(always)]
+    pub fn remove(&mut self, i: usize) -> T {
+        self.data.remove(i)
+    }
+    /// shortens the vector to `len` elements, does nothing if it is already shorter
+    #[inline(always)]
+    pub fn truncate(&mut self, len: usize) {
+        self.data.truncate(len)
+    }
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.data.clear()
+    }
+}
+
+impl<T> Vector<T>
+    where T: Clone,
+{
+    /// resizes the vector to `len` elements, filling any new ones with `value`
+    #[inline(always)]
+    pub fn resize(&mut self, len: usize, value: T) {
+        self.data.resize(len, value)
+    }
+    #[inline(always)]
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.data.extend_from_slice(other)
+    }
+}
+
+impl<T> Extend<T> for Vector<T> {
+    #[inline(always)]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.data.extend(iter)
+    }
+}
+
+impl<T> FromIterator<T> for Vector<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Vector {
+            data: iter.into_iter().collect(),
         }
     }
 }
 
+impl<T> IntoIterator for Vector<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+impl<'a, T: 'a> IntoIterator for &'a Vector<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+impl<'a, T: 'a> IntoIterator for &'a mut Vector<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
 impl<T> Deref for Vector<T> {
     type Target = [T];
 
@@ -103,25 +253,137 @@ impl<T: fmt::Debug> fmt::Debug for Vector<T> {
     }
 }
 
-impl<'a, 'b, T> Vector<T>
-    where T: 'a + 'b + Zero + AddAssign<T>,
-          &'a T: Mul<&'b T, Output = T>,
-{
+impl<T: Zero + AddAssign<T>> Vector<T> {
+    /// the kernel of `dot` with the element product given by `product`, for element types
+    /// such as a generic `Float` whose references are not known to multiply
     #[inline]
-    pub fn dot(&'a self, other: &'b Vector<T>) -> T {
+    pub(crate) fn dot_with<'a, 'b, F>(&'a self, other: &'b Vector<T>, mut product: F) -> T
+        where F: FnMut(&'a T, &'b T) -> T,
+    {
         let len = self.len();
         let other_len = other.len();
         assert!(len == other_len, "a * b, a's length does not match b's length");
         let mut out = T::zero();
 
         for i in 0..len {
-            out += &self[i] * &other[i];
+            out += product(&self[i], &other[i]);
     	}
 
         out
     }
 }
 
+impl<'a, 'b, T> Vector<T>
+    where T: 'a + 'b + Zero + AddAssign<T>,
+          &'a T: Mul<&'b T, Output = T>,
+{
+    #[inline]
+    pub fn dot(&'a self, other: &'b Vector<T>) -> T {
+        self.dot_with(other, |x, y| x * y)
+    }
+}
+
+impl<T> Vector<T> {
+    #[inline]
+    pub fn map<U, F>(&self, f: F) -> Vector<U>
+        where F: FnMut(&T) -> U,
+    {
+        self.iter().map(f).collect()
+    }
+    #[inline]
+    pub fn map_inplace<F>(&mut self, f: F)
+        where F: FnMut(&mut T),
+    {
+        self.iter_mut().for_each(f);
+    }
+    #[inline]
+    pub fn zip_map<U, V, F>(&self, other: &Vector<U>, mut f: F) -> Vector<V>
+        where F: FnMut(&T, &U) -> V,
+    {
+        let len = self.len();
+        assert!(len == other.len(), "a, b zip, a's length does not match b's length");
+        Vector::from_fn(len, |i| f(&self[i], &other[i]))
+    }
+    #[inline]
+    pub fn zip_apply<U, F>(&mut self, other: &Vector<U>, mut f: F)
+        where F: FnMut(&mut T, &U),
+    {
+        let len = self.len();
+        assert!(len == other.len(), "a, b zip, a's length does not match b's length");
+        for i in 0..len {
+            f(&mut self[i], &other[i]);
+        }
+    }
+    #[inline]
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+        where F: FnMut(B, &T) -> B,
+    {
+        self.iter().fold(init, f)
+    }
+}
+
+impl<T> Vector<T>
+    where T: PartialOrd,
+{
+    /// returns the smallest element, or `None` if the vector is empty
+    #[inline]
+    pub fn min(&self) -> Option<&T> {
+        let mut out: Option<&T> = None;
+        for x in self.iter() {
+            match out {
+                Some(m) if x < m => out = Some(x),
+                None => out = Some(x),
+                _ => (),
+            }
+        }
+        out
+    }
+    /// returns the largest element, or `None` if the vector is empty
+    #[inline]
+    pub fn max(&self) -> Option<&T> {
+        self.argmax().map(|i| &self[i])
+    }
+    /// returns the index of the first largest element, or `None` if the vector is empty
+    #[inline]
+    pub fn argmax(&self) -> Option<usize> {
+        let mut out = None;
+        for i in 0..self.len() {
+            match out {
+                Some(j) if self[i] > self[j] => out = Some(i),
+                None => out = Some(i),
+                _ => (),
+            }
+        }
+        out
+    }
+}
+
+impl<T> Vector<T>
+    where T: Zero + Clone + AddAssign<T>,
+{
+    #[inline]
+    pub fn sum(&self) -> T {
+        let mut out = T::zero();
+        for x in self.iter() {
+            out += x.clone();
+        }
+        out
+    }
+}
+
+impl<T> Vector<T>
+    where T: One + Clone + MulAssign<T>,
+{
+    #[inline]
+    pub fn product(&self) -> T {
+        let mut out = T::one();
+        for x in self.iter() {
+            out *= x.clone();
+        }
+        out
+    }
+}
+
 macro_rules! impl_bin_op {
     ($Trait: ident, $trait: ident, $name: ident, $matrix: ident, $scalar: ident, $op: tt) => (
         impl<'out, 'a, 'b, T> Vector<T>
@@ -165,7 +427,7 @@ macro_rules! impl_bin_op {
         }
 
         impl<'a, 'b, T> $Trait<&'b Vector<T>> for  &'a Vector<T>
-            where T: 'a + 'b,
+            where T: 'a + 'b + Zero,
                   &'a T: $Trait<&'b T, Output = T>,
         {
             type Output = Vector<T>;
@@ -179,7 +441,7 @@ macro_rules! impl_bin_op {
         }
 
         impl<'a, 'b, T> $Trait<&'b Matrix<T>> for  &'a Vector<T>
-            where T: 'a + 'b,
+            where T: 'a + 'b + Zero,
                   &'a T: $Trait<&'b T, Output = T>,
         {
             type Output = Vector<T>;
@@ -193,7 +455,7 @@ macro_rules! impl_bin_op {
         }
 
         impl<'a, 'b, T> $Trait<&'b T> for &'a Vector<T>
-            where T: 'a + 'b,
+            where T: 'a + 'b + Zero,
                   &'a T: $Trait<&'b T, Output = T>,
         {
             type Output = Vector<T>;
@@ -221,11 +483,7 @@ impl<'a, T> Neg for &'a Vector<T>
 
     #[inline]
     fn neg(self) -> Self::Output {
-        let mut out = Vector::zeroed(self.len());
-        for i in 0..self.len() {
-            out[i] = -&self[i];
-        }
-        out
+        Vector::from_fn(self.len(), |i| -&self[i])
     }
 }
 
@@ -313,6 +571,103 @@ mod test {
         assert_eq!(b[1], -1);
         assert_eq!(b[2], -1);
     }
+    #[test]
+    fn test_get_vector() {
+        let mut a: Vector<usize> = vec_ones(3);
+        assert_eq!(a.get(2), Some(&1));
+        assert_eq!(a.get(3), None);
+
+        *a.get_mut(0).unwrap() = 5;
+        assert_eq!(a.get_mut(3), None);
+        assert_eq!(unsafe { *a.get_unchecked(0) }, 5);
+    }
+    #[test]
+    fn test_empty_vector() {
+        let a: Vector<isize> = Vector::zeroed(0);
+        let b: Vector<isize> = Vector::new(0);
+        assert!(a.is_empty());
+        assert_eq!(&a + &b, a);
+        assert_eq!(&a * &2, a);
+        assert_eq!(-&a, a);
+        assert_eq!(a.dot(&b), 0);
+        assert_eq!(a.sum(), 0);
+        assert_eq!(a.product(), 1);
+        assert_eq!(a.max(), None);
+        assert_eq!(a.argmax(), None);
+    }
+    #[test]
+    fn test_into_matrix_vector() {
+        let a: Vector<usize> = Vector::from_fn(6, |i| i);
+        assert_eq!(a.clone().into_matrix(2, 3), Matrix::from_row_slice(2, 3, &[0, 1, 2, 3, 4, 5]));
+        assert_eq!(a.clone().into_matrix(6, 1).flatten(), a);
+        assert_eq!(Vector::<usize>::new(0).into_matrix(0, 3), Matrix::zeroed(0, 3));
+    }
+    #[test]
+    #[should_panic(expected = "a 2x2 matrix can not hold 3 elements")]
+    fn test_into_matrix_wrong_length() {
+        vec_ones::<usize>(3).into_matrix(2, 2);
+    }
+    #[test]
+    #[should_panic(expected = "matrix can not hold 0 elements")]
+    fn test_into_matrix_overflow() {
+        Vector::<usize>::new(0).into_matrix(usize::MAX / 2 + 1, 2);
+    }
+    #[test]
+    fn test_grow_vector() {
+        let mut a: Vector<usize> = Vector::with_capacity(2);
+        a.reserve(8);
+        assert!(a.capacity() >= 8);
+
+        a.push(1);
+        a.push(3);
+        a.insert(1, 2);
+        a.extend(4..6);
+        assert_eq!(&*a, &[1, 2, 3, 4, 5]);
+
+        assert_eq!(a.pop(), Some(5));
+        assert_eq!(a.remove(0), 1);
+        a.truncate(2);
+        assert_eq!(&*a, &[2, 3]);
+
+        a.resize(4, 7);
+        a.extend_from_slice(&[8]);
+        assert_eq!(&*a, &[2, 3, 7, 7, 8]);
+
+        a.clear();
+        assert!(a.is_empty());
+    }
+    #[test]
+    fn test_map_vector() {
+        let a: Vector<isize> = vec_ones(3);
+        let b: Vector<f32> = a.map(|x| *x as f32 * 2.0);
+        assert_eq!(&*b, &[2.0, 2.0, 2.0]);
+
+        let mut c = a.clone();
+        c.map_inplace(|x| *x = -*x);
+        assert_eq!(c, -&a);
+    }
+    #[test]
+    fn test_zip_vectors() {
+        let mut a: Vector<usize> = vec_ones(3);
+        a[2] = 4;
+        let b: Vector<usize> = vec_ones(3);
+        assert_eq!(a.zip_map(&b, |x, y| x + y), &a + &b);
+
+        a.zip_apply(&b, |x, y| *x -= *y);
+        assert_eq!(&*a, &[0, 0, 3]);
+    }
+    #[test]
+    fn test_fold_vector() {
+        let mut a: Vector<isize> = vec_ones(4);
+        a[1] = 3;
+        a[3] = -2;
+        assert_eq!(a.fold(0, |acc, x| acc + x * x), 15);
+        assert_eq!(a.sum(), 3);
+        assert_eq!(a.product(), -6);
+        assert_eq!(a.min(), Some(&-2));
+        assert_eq!(a.max(), Some(&3));
+        assert_eq!(a.argmax(), Some(1));
+    }
 
 
     fn vec_zeros<T: Default + Zero>(count: usize) -> Vector<T> {
@@ -0,0 +1,197 @@
+use core::fmt;
+use core::ops::Index;
+use core::slice;
+
+use super::matrix::Matrix;
+
+
+/// a borrowed matrix over a contiguous row-major slice, for example a memory-mapped file
+pub struct MatrixView<'a, T: 'a> {
+    rows: usize,
+    cols: usize,
+    data: &'a [T],
+}
+
+impl<'a, T: 'a> Clone for MatrixView<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self { *self }
+}
+impl<'a, T: 'a> Copy for MatrixView<'a, T> {}
+
+impl<'a, T: 'a> MatrixView<'a, T> {
+    /// views `data` as a `rows x cols` matrix in row-major order, panics if `data` does not
+    /// hold exactly `rows * cols` elements
+    #[inline]
+    pub fn new(rows: usize, cols: usize, data: &'a [T]) -> Self {
+        assert!(rows.checked_mul(cols) == Some(data.len()),
+            "slice has length {}, expected {} for a {}x{} matrix", data.len(), rows.wrapping_mul(cols), rows, cols);
+
+        MatrixView {
+            rows: rows,
+            cols: cols,
+            data: data,
+        }
+    }
+
+    #[inline(always)]
+    pub fn rows(&self) -> usize { self.rows }
+    #[inline(always)]
+    pub fn cols(&self) -> usize { self.cols }
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.rows == 0 || self.cols == 0 }
+
+    /// the elements in row-major order
+    #[inline(always)]
+    pub fn as_slice(&self) -> &'a [T] { self.data }
+
+    #[inline]
+    pub fn get(&self, i: usize, j: usize) -> Option<&'a T> {
+        if i < self.rows && j < self.cols {
+            Some(&self.data[i * self.cols + j])
+        } else {
+            None
+        }
+    }
+    #[inline]
+    pub fn row(&self, i: usize) -> &'a [T] {
+        assert!(i < self.rows, "row index {} out of range for a {}x{} matrix", i, self.rows, self.cols);
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// iterates over the rows as slices
+    #[inline]
+    pub fn row_iter(&self) -> RowIter<'a, T> {
+        RowIter {
+            data: self.data,
+            cols: self.cols,
+            rows: self.rows,
+        }
+    }
+    /// iterates over every element in row-major order
+    #[inline(always)]
+    pub fn iter(&self) -> slice::Iter<'a, T> {
+        self.data.iter()
+    }
+}
+
+impl<'a, T: 'a + Clone> MatrixView<'a, T> {
+    /// copies the view into an owned `Matrix`
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| self.data[i * self.cols + j].clone())
+    }
+}
+
+impl<'a, T: 'a> Index<usize> for MatrixView<'a, T> {
+    type Output = [T];
+
+    #[inline(always)]
+    fn index(&self, index: usize) -> &Self::Output {
+        self.row(index)
+    }
+}
+impl<'a, T: 'a> Index<(usize, usize)> for MatrixView<'a, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        match self.get(i, j) {
+            Some(x) => x,
+            None => panic!("index ({}, {}) out of range for a {}x{} matrix", i, j, self.rows, self.cols),
+        }
+    }
+}
+
+impl<'a, 'b, T: PartialEq> PartialEq<MatrixView<'b, T>> for MatrixView<'a, T> {
+    #[inline]
+    fn eq(&self, other: &MatrixView<'b, T>) -> bool {
+        self.rows == other.rows && self.cols == other.cols && self.data == other.data
+    }
+}
+impl<'a, T: PartialEq> PartialEq<Matrix<T>> for MatrixView<'a, T> {
+    #[inline]
+    fn eq(&self, other: &Matrix<T>) -> bool {
+        self.rows == other.rows() && self.cols == other.cols() && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for MatrixView<'a, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.row_iter()).finish()
+    }
+}
+
+
+/// iterates over the rows of a `MatrixView` as slices
+pub struct RowIter<'a, T: 'a> {
+    data: &'a [T],
+    cols: usize,
+    rows: usize,
+}
+
+impl<'a, T: 'a> Iterator for RowIter<'a, T> {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            None
+        } else {
+            let (row, rest) = self.data.split_at(self.cols);
+            self.data = rest;
+            self.rows -= 1;
+            Some(row)
+        }
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rows, Some(self.rows))
+    }
+}
+impl<'a, T: 'a> DoubleEndedIterator for RowIter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            None
+        } else {
+            let (rest, row) = self.data.split_at(self.data.len() - self.cols);
+            self.data = rest;
+            self.rows -= 1;
+            Some(row)
+        }
+    }
+}
+impl<'a, T: 'a> ExactSizeIterator for RowIter<'a, T> {}
+
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+
+    #[test]
+    fn test_view() {
+        let data = [1, 2, 3, 4, 5, 6];
+        let view = MatrixView::new(2, 3, &data);
+
+        assert_eq!((view.rows(), view.cols()), (2, 3));
+        assert_eq!(view[1], [4, 5, 6]);
+        assert_eq!(view[(0, 2)], 3);
+        assert_eq!(view.get(2, 0), None);
+        assert_eq!(view.row_iter().rev().map(|row| row[0]).collect::<Vec<_>>(), [4, 1]);
+        assert_eq!(view, Matrix::from([[1, 2, 3], [4, 5, 6]]));
+        assert_eq!(view.to_matrix(), Matrix::from([[1, 2, 3], [4, 5, 6]]));
+
+        let empty = MatrixView::<f32>::new(0, 4, &[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.row_iter().count(), 0);
+    }
+    #[test]
+    #[should_panic]
+    fn test_view_wrong_len() {
+        MatrixView::new(2, 2, &[1, 2, 3]);
+    }
+}